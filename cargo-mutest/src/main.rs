@@ -8,6 +8,8 @@ pub mod build {
     pub const RUST_TOOLCHAIN_VERSION: &str = env!("RUST_TOOLCHAIN_VERSION");
 }
 
+mod package_config;
+
 fn strip_arg(args: &mut Vec<String>, has_value: bool, short_arg: Option<&str>, long_arg: Option<&str>) {
     let short_arg = short_arg.map(|v| format!("-{v}"));
     let long_arg = long_arg.map(|v| format!("--{v}"));
@@ -52,7 +54,15 @@ fn test_strip_arg() {
 mod run_isolate {
     mutest_driver_cli::exclusive_opts! { pub(crate) possible_values where
         UNSAFE = "unsafe"; ["Only isolate tests for unsafe mutations."]
-        ALL = "all"; ["Isolate tests for all mutations."]
+        ALL = "all"; ["Isolate tests for all mutations. Each test is run in its own process, which attributes crashes and timeouts to individual tests, but is considerably slower."]
+    }
+}
+
+mod run_count {
+    mutest_driver_cli::exclusive_opts! { pub(crate) possible_values where
+        DETECTED = "detected"; ["Count the mutations as detected in mutation scores."]
+        UNDETECTED = "undetected"; ["Count the mutations as undetected in mutation scores."]
+        SEPARATE = "separate"; ["Exclude the mutations from mutation scores, and only report them separately."]
     }
 }
 
@@ -60,7 +70,53 @@ mod run_print {
     mutest_driver_cli::opts! { ALL, pub(crate) possible_values where
         DETECTION_MATRIX = "detection-matrix"; ["Print test-mutation detection matrix."]
         SUBSUMPTION_MATRIX = "subsumption-matrix"; ["Print mutation subsumption matrix."]
+        TEST_EFFECTIVENESS = "test-effectiveness"; ["Print tests ranked by the number of mutations they detect uniquely, and in total."]
+    }
+}
+
+mod run_sort {
+    mutest_driver_cli::exclusive_opts! { pub(crate) possible_values where
+        BY_ID = "by-id"; ["Order rows by mutation ID, and tests by name."]
+        BY_FILE = "by-file"; ["Order rows by the source location of mutations, and tests by name."]
+        BY_SCORE = "by-score"; ["Order rows by the number of mutations subsumed by mutations, and detected by tests."]
+    }
+}
+
+mod run_write_json_filter {
+    mutest_driver_cli::exclusive_opts! { pub(crate) possible_values where
+        DETECTED = "detected"; ["Mutations detected by a failing test."]
+        UNDETECTED = "undetected"; ["Mutations not detected by any test."]
+        TIMED_OUT = "timed-out"; ["Mutations detected by a test timing out."]
+        CRASHED = "crashed"; ["Mutations detected by a test crashing."]
+    }
+}
+
+mod color {
+    mutest_driver_cli::exclusive_opts! { pub(crate) possible_values where
+        AUTO = "auto"; ["Color output if printing to a terminal, unless the `NO_COLOR` environment variable is set."]
+        ALWAYS = "always"; ["Always color output."]
+        NEVER = "never"; ["Never color output."]
+    }
+}
+
+/// Returns the value of the `--color` option, if specified.
+///
+/// The color choice is needed before the arguments are parsed, so that help and error messages are printed accordingly.
+fn color_arg(args: &[String]) -> Option<&str> {
+    let mut args = args.iter().take_while(|arg| *arg != "--");
+    while let Some(arg) = args.next() {
+        if arg == "--color" { return args.next().map(String::as_str); }
+        if let Some(color) = arg.strip_prefix("--color=") { return Some(color); }
     }
+    None
+}
+
+#[test]
+fn test_color_arg() {
+    assert_eq!(None, color_arg(&["run".to_owned()]));
+    assert_eq!(Some("never"), color_arg(&["--color".to_owned(), "never".to_owned(), "run".to_owned()]));
+    assert_eq!(Some("always"), color_arg(&["run".to_owned(), "--color=always".to_owned()]));
+    assert_eq!(None, color_arg(&["run".to_owned(), "--".to_owned(), "--color=always".to_owned()]));
 }
 
 #[cfg(not(windows))]
@@ -82,8 +138,16 @@ fn cargo_command_base() -> Command {
 fn main() {
     let args = env::args().skip(2).collect::<Vec<_>>();
 
+    let (color_choice, anstream_color_choice) = match color_arg(&args) {
+        Some(color::ALWAYS) => (clap::ColorChoice::Always, anstream::ColorChoice::Always),
+        Some(color::NEVER) => (clap::ColorChoice::Never, anstream::ColorChoice::Never),
+        _ => (clap::ColorChoice::Auto, anstream::ColorChoice::Auto),
+    };
+    anstream::ColorChoice::write_global(anstream_color_choice);
+
     let matches = mutest_driver_cli::command()
         .bin_name("cargo mutest")
+        .color(color_choice)
         .no_binary_name(true)
         .about("Mutation testing tools for Rust")
         // Subcommands
@@ -91,18 +155,28 @@ fn main() {
             .display_order(0)
             .about("Build and run the test harness.")
             // Evaluation-related Arguments
-            .arg(clap::arg!(--simulate [MUTATION_ID] "Evaluate tests for a single mutation.").value_parser(clap::value_parser!(u32)).conflicts_with_all(["flakes", "exhaustive", "print"]).display_order(110))
+            .arg(clap::arg!(--simulate [MUTATION_ID] "Evaluate tests for a single mutation.").value_parser(clap::value_parser!(u32)).conflicts_with_all(["flakes", "exhaustive", "fail-fast", "checkpoint", "print"]).display_order(110))
             .arg(clap::arg!(--flakes [ITERATIONS_COUNT] "Perform mutation analysis multiple times to find flaky test-mutation pairs.").value_parser(clap::value_parser!(usize)).display_order(111))
             .arg(clap::arg!(--exhaustive "Evaluate remaining tests, even if the mutation has already been detected by another test.").display_order(115))
+            .arg(clap::arg!(--"fail-fast" "Stop evaluating mutations after the first undetected mutation.").conflicts_with_all(["flakes", "exhaustive"]).display_order(116))
             .arg(clap::arg!(--isolate [ISOLATION_MODE] "Isolate tests of mutations into separate processes.").value_parser(run_isolate::possible_values()).default_value(run_isolate::UNSAFE).display_order(120))
-            .arg(clap::arg!(--"use-thread-pool" "Evaluate tests in a fixed-size thread pool.").display_order(120))
+            .arg(clap::arg!(--"use-thread-pool" "Evaluate tests in a fixed-size thread pool.").overrides_with("no-use-thread-pool").display_order(120))
+            .arg(clap::arg!(--"no-use-thread-pool" "Do not evaluate tests in a fixed-size thread pool, even if enabled in the Cargo manifest.").overrides_with("use-thread-pool").display_order(120))
+            .arg(clap::arg!(--"test-timeout" [SECONDS] "Timeout for each test run against a mutation. By default, the timeout is deduced from the execution time of the test in the unmutated reference run.").value_parser(clap::value_parser!(f64)).display_order(121))
+            .arg(clap::arg!(--"deterministic-order" "Evaluate mutations in the order of their content hashes, so that repeated runs evaluate mutations in the same order, regardless of their IDs. Note that tests of a mutation may still complete in a different order when run in parallel.").display_order(120))
+            .arg(clap::arg!(--"count-crashes" [COUNTING] "How mutations detected by a test crashing are counted in mutation scores.").value_parser(run_count::possible_values()).default_value(run_count::DETECTED).display_order(122))
+            .arg(clap::arg!(--"count-timeouts" [COUNTING] "How mutations detected by a test timing out are counted in mutation scores.").value_parser(run_count::possible_values()).default_value(run_count::DETECTED).display_order(122))
+            .arg(clap::arg!(--checkpoint [CHECKPOINT_FILE] "Record evaluated mutations in the checkpoint file, and resume an interrupted evaluation from it. All recorded results are discarded if the test binary changed since.").value_parser(clap::value_parser!(PathBuf)).conflicts_with("flakes").display_order(125))
             // Printing-related Arguments
             .arg(clap::arg!(--print [PRINT] "Print additional information during mutation evaluation. Multiple may be specified, separated by commas.").value_delimiter(',').value_parser(run_print::possible_values()).display_order(101))
+            .arg(clap::arg!(--sort [ORDER] "Order of the rows of printed matrices.").value_parser(run_sort::possible_values()).default_value(run_sort::BY_ID).display_order(102))
             // Experimental Flags
             .arg(clap::arg!(--"Zwrite-json-eval-stream" "Write JSONL stream file into JSON output directory specified by `--Zwrite-json`.").display_order(500))
+            .arg(clap::arg!(--"Zwrite-json-filter" [DETECTIONS] "Write the mutations with the specified detection statuses into a `filtered_mutations.json` file in the JSON output directory specified by `--Zwrite-json`. Multiple may be specified, separated by commas.").value_delimiter(',').value_parser(run_write_json_filter::possible_values()).conflicts_with("flakes").display_order(500))
             // Passed arguments
             .arg(clap::Arg::new("PASSED_ARGS").trailing_var_arg(true).allow_hyphen_values(true))
         )
+        .arg(clap::arg!(--color [WHEN] "Coloring of output.").value_parser(color::possible_values()).global(true))
         // Cargo options.
         .next_help_heading("Package Selection")
         .arg(clap::arg!(--workspace "Test all packages in the workspace."))
@@ -128,12 +202,38 @@ fn main() {
         .next_help_heading("Manifest Options")
         .arg(clap::arg!(--"manifest-path" [MANIFEST_PATH] "Path to Cargo.toml."))
         .arg(clap::arg!(--offline "Run without accessing the network."))
+        .arg(clap::arg!(--frozen "Require Cargo.lock and cache are up to date, and run without accessing the network."))
+        .arg(clap::arg!(--locked "Require Cargo.lock is up to date."))
         .after_help(color_print::cstr!("Run `<bright-cyan,bold>cargo mutest run -h</>` to display additional options that can be specified for the running test harness."))
         .after_long_help(color_print::cstr!("Run `<bright-cyan,bold>cargo mutest help run</>` to display additional options that can be specified for the running test harness."))
         .get_matches_from(&args);
 
     let embedded = matches.get_flag("Zembedded");
 
+    let cargo_manifest_flags = ["offline", "frozen", "locked"].into_iter()
+        .filter(|flag| matches.get_flag(flag))
+        .map(|flag| format!("--{flag}"))
+        .collect::<Vec<_>>();
+
+    // NOTE: Options specified on the command line take precedence over the defaults specified in the Cargo manifest.
+    //       These defaults only apply to the options of the non-embedded test harness.
+    let run_config = match matches.subcommand_name() {
+        Some("run") if !embedded => {
+            let manifest_path = matches.get_one::<String>("manifest-path").map(String::as_str);
+            let package_name = matches.get_one::<String>("package").map(String::as_str);
+            let cargo_flags = cargo_manifest_flags.iter().map(String::as_str).collect::<Vec<_>>();
+
+            match package_config::fetch_run_config(manifest_path, package_name, &cargo_flags) {
+                Ok(run_config) => run_config,
+                Err(error) => {
+                    anstream::eprintln!("{}", color_print::cformat!("<red,bold>error</>: {error}"));
+                    process::exit(101);
+                }
+            }
+        }
+        _ => Default::default(),
+    };
+
     let (cargo_subcommand, cargo_args, mutest_driver_subcommand, passed_args): (_, &[&str], _, _) = match matches.subcommand() {
         Some(("print", _)) => ("check", &["--profile", "test"], "print", None),
         Some(("build", _)) => ("test", &["--no-run"], "build", None),
@@ -146,15 +246,44 @@ fn main() {
             if matches.get_flag("exhaustive") { passed_args.push("--exhaustive".to_owned()); }
 
             if !embedded {
-                if let Some(isolation_mode) = matches.get_one::<String>("isolate") { passed_args.push(format!("--isolate={isolation_mode}")); }
-                if matches.get_flag("use-thread-pool") { passed_args.push("--use-thread-pool".to_owned()); }
+                let isolation_mode = match matches.value_source("isolate") {
+                    Some(clap::parser::ValueSource::CommandLine) => matches.get_one::<String>("isolate").map(String::as_str),
+                    _ => run_config.isolate.or(matches.get_one::<String>("isolate").map(String::as_str)),
+                };
+                if let Some(isolation_mode) = isolation_mode { passed_args.push(format!("--isolate={isolation_mode}")); }
+                let use_thread_pool = match () {
+                    _ if matches.get_flag("use-thread-pool") => true,
+                    _ if matches.get_flag("no-use-thread-pool") => false,
+                    _ => run_config.use_thread_pool.unwrap_or(false),
+                };
+                if use_thread_pool { passed_args.push("--use-thread-pool".to_owned()); }
+                if matches.get_flag("deterministic-order") { passed_args.push("--deterministic-order".to_owned()); }
+                if let Some(test_timeout) = matches.get_one::<f64>("test-timeout").copied().or(run_config.test_timeout) { passed_args.push(format!("--test-timeout={test_timeout}")); }
+                if matches.get_flag("fail-fast") { passed_args.push("--fail-fast".to_owned()); }
+                if let Some(crashes_counting) = matches.get_one::<String>("count-crashes") { passed_args.push(format!("--count-crashes={crashes_counting}")); }
+                if let Some(timeouts_counting) = matches.get_one::<String>("count-timeouts") { passed_args.push(format!("--count-timeouts={timeouts_counting}")); }
+                if let Some(color) = matches.get_one::<String>("color") { passed_args.push(format!("--color={color}")); }
+                if let Some(checkpoint_path) = matches.get_one::<PathBuf>("checkpoint") {
+                    // NOTE: The test harness is run from the package directory, so relative paths must be resolved beforehand.
+                    let checkpoint_path = std::path::absolute(checkpoint_path).expect("invalid checkpoint file path");
+                    passed_args.push(format!("--checkpoint={}", checkpoint_path.display()));
+                }
             }
 
             let mut print_names = matches.get_many::<String>("print").map(|print| print.map(String::as_str).collect::<HashSet<_>>()).unwrap_or_default();
             if print_names.contains("all") { print_names = HashSet::from_iter(run_print::ALL.into_iter().map(|s| *s)); }
+            // NOTE: The embedded test harness does not support printing test effectiveness.
+            if embedded { print_names.remove(run_print::TEST_EFFECTIVENESS); }
             for print_name in print_names { passed_args.push(format!("--print={print_name}")); }
 
+            if !embedded {
+                if let Some(matrix_ordering) = matches.get_one::<String>("sort") { passed_args.push(format!("--sort={matrix_ordering}")); }
+            }
+
             if matches.get_flag("Zwrite-json-eval-stream") { passed_args.push("--Zwrite-json-eval-stream".to_owned()); }
+            if !embedded {
+                for detection in matches.get_many::<String>("Zwrite-json-filter").unwrap_or_default() { passed_args.push(format!("--Zwrite-json-filter={detection}")); }
+            }
 
             ("test", &[], "build", Some(passed_args))
         }
@@ -176,8 +305,8 @@ fn main() {
                 let output = config_cmd.output().expect("failed to run Cargo");
 
                 if !output.status.success() {
-                    color_print::ceprintln!("<red,bold>error</>: target must be specified when using the embedded mutation runtime");
-                    color_print::ceprintln!("       consider specifying `build.target` in `.cargo/config.toml` or using the `--target` option");
+                    anstream::eprintln!("{}", color_print::cstr!("<red,bold>error</>: target must be specified when using the embedded mutation runtime"));
+                    anstream::eprintln!("{}", color_print::cstr!("       consider specifying `build.target` in `.cargo/config.toml` or using the `--target` option"));
                     process::exit(101);
                 }
 
@@ -200,8 +329,8 @@ fn main() {
             Err(_) => {}
 
             Ok(false) => {
-                color_print::ceprintln!("<red,bold>error</>: cannot find mutest-rs embedded runtime host driver");
-                color_print::ceprintln!("       consider running `cargo install --force --path mutest-runtime-embedded-host-driver` in the mutest-rs source tree");
+                anstream::eprintln!("{}", color_print::cstr!("<red,bold>error</>: cannot find mutest-rs embedded runtime host driver"));
+                anstream::eprintln!("{}", color_print::cstr!("       consider running `cargo install --force --path mutest-runtime-embedded-host-driver` in the mutest-rs source tree"));
                 process::exit(101);
             }
         }
@@ -336,9 +465,16 @@ fn main() {
         cmd.args(["--lib", "--bins", "--examples", "--tests"]);
     }
 
-    if matches.get_flag("offline") {
-        cmd.arg("--offline");
-        strip_arg(&mut mutest_args, false, None, Some("offline"));
+    if let Some(color) = matches.get_one::<String>("color") {
+        cmd.args(["--color", color]);
+        strip_arg(&mut mutest_args, true, None, Some("color"));
+    }
+
+    for flag in ["offline", "frozen", "locked"] {
+        if matches.get_flag(flag) {
+            cmd.arg(format!("--{flag}"));
+            strip_arg(&mut mutest_args, false, None, Some(flag));
+        }
     }
 
     let mut path = env::current_exe().expect("current executable path invalid");
@@ -360,6 +496,7 @@ fn main() {
             let out_dir = out_dir.canonicalize().expect("cannot canonicalize out dir path");
             let out_dir = out_dir.as_os_str().to_str().expect("non-UTF-8 path");
             cmd.arg(format!("--Zwrite-json={out_dir}"));
+            if matches.get_flag("Zwrite-json-compress") { cmd.arg("--Zwrite-json-compress"); }
         }
         cmd.args(&passed_args);
     }
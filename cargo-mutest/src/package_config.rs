@@ -0,0 +1,98 @@
+use std::fmt;
+
+use mutest_driver_cli::package_config::{CargoMutestMetadata, MutationIsolation};
+
+use crate::run_isolate;
+
+/// Defaults for the options of `cargo mutest run`, read from the
+/// `package.metadata.mutest` and `workspace.metadata.mutest` tables of the Cargo manifest.
+#[derive(Debug, Default, PartialEq)]
+pub struct RunConfig {
+    pub isolate: Option<&'static str>,
+    pub use_thread_pool: Option<bool>,
+    pub test_timeout: Option<f64>,
+}
+
+#[derive(Debug)]
+pub enum RunConfigError {
+    Metadata(cargo_metadata::Error),
+    Parse { table_name: &'static str, error: serde_path_to_error::Error<serde_json::Error> },
+    InvalidValue { table_name: &'static str, key: &'static str, expected: &'static str },
+}
+
+impl fmt::Display for RunConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Metadata(error) => write!(f, "could not retrieve Cargo metadata: {error}"),
+            Self::Parse { table_name, error } => write!(f, "{error} (while parsing `{table_name}` in Cargo manifest)"),
+            Self::InvalidValue { table_name, key, expected } => write!(f, "invalid value for `{key}` in `{table_name}` in Cargo manifest, expected {expected}"),
+        }
+    }
+}
+
+fn parse_run_config(mutest_metadata: &serde_json::Value, table_name: &'static str) -> Result<RunConfig, RunConfigError> {
+    // NOTE: The whole table is parsed, using the same definitions as the driver,
+    //       so that invalid options are reported consistently, before anything is built.
+    let mutest_metadata = serde_path_to_error::deserialize::<_, CargoMutestMetadata>(mutest_metadata)
+        .map_err(|error| RunConfigError::Parse { table_name, error })?;
+
+    if let Some(test_timeout) = mutest_metadata.test_timeout && test_timeout <= 0.0 {
+        return Err(RunConfigError::InvalidValue { table_name, key: "test-timeout", expected: "a positive number of seconds" });
+    }
+
+    Ok(RunConfig {
+        isolate: mutest_metadata.isolate.map(|isolate| match isolate {
+            MutationIsolation::Unsafe => run_isolate::UNSAFE,
+            MutationIsolation::All => run_isolate::ALL,
+        }),
+        use_thread_pool: mutest_metadata.use_thread_pool,
+        test_timeout: mutest_metadata.test_timeout,
+    })
+}
+
+/// Reads the defaults for `cargo mutest run` from the Cargo manifest of the selected package, and its workspace.
+///
+/// Options specified in the package take precedence over options specified in the workspace.
+/// The `cargo_flags` (e.g. `--offline`) are passed to `cargo metadata`.
+pub fn fetch_run_config(manifest_path: Option<&str>, package_name: Option<&str>, cargo_flags: &[&str]) -> Result<RunConfig, RunConfigError> {
+    let mut metadata_cmd = cargo_metadata::MetadataCommand::new();
+    if let Some(manifest_path) = manifest_path { metadata_cmd.manifest_path(manifest_path); }
+    metadata_cmd.no_deps();
+    metadata_cmd.other_options(cargo_flags.iter().map(|&flag| flag.to_owned()).collect::<Vec<_>>());
+
+    let metadata = metadata_cmd.exec().map_err(RunConfigError::Metadata)?;
+
+    let package = match package_name {
+        Some(package_name) => metadata.packages.iter().find(|package| package.name.as_ref() == package_name),
+        None => metadata.root_package(),
+    };
+
+    let package_run_config = match package.and_then(|package| package.metadata.get("mutest")) {
+        Some(mutest_metadata) => parse_run_config(mutest_metadata, "package.metadata.mutest")?,
+        None => Default::default(),
+    };
+    let workspace_run_config = match metadata.workspace_metadata.get("mutest") {
+        Some(mutest_metadata) => parse_run_config(mutest_metadata, "workspace.metadata.mutest")?,
+        None => Default::default(),
+    };
+
+    Ok(RunConfig {
+        isolate: package_run_config.isolate.or(workspace_run_config.isolate),
+        use_thread_pool: package_run_config.use_thread_pool.or(workspace_run_config.use_thread_pool),
+        test_timeout: package_run_config.test_timeout.or(workspace_run_config.test_timeout),
+    })
+}
+
+#[test]
+fn test_parse_run_config() {
+    assert_eq!(RunConfig::default(), parse_run_config(&serde_json::json!({ "mutation-depth": 2 }), "package.metadata.mutest").unwrap());
+
+    assert_eq!(
+        RunConfig { isolate: Some(run_isolate::ALL), use_thread_pool: Some(true), test_timeout: Some(2.5) },
+        parse_run_config(&serde_json::json!({ "isolate": "all", "use-thread-pool": true, "test-timeout": 2.5 }), "package.metadata.mutest").unwrap(),
+    );
+
+    assert!(parse_run_config(&serde_json::json!({ "isolate": "none" }), "package.metadata.mutest").is_err());
+    assert!(parse_run_config(&serde_json::json!({ "use-thread-pool": "yes" }), "package.metadata.mutest").is_err());
+    assert!(parse_run_config(&serde_json::json!({ "test-timeout": 0 }), "package.metadata.mutest").is_err());
+}
@@ -2,6 +2,8 @@
 
 use std::path::PathBuf;
 
+pub mod package_config;
+
 pub macro opts(
     $all:ident, $possible_values_vis:vis $possible_values:ident where
     $($(#[$attr:meta])* $ident:ident = $name:expr; $([$help:expr])?)*
@@ -36,18 +38,25 @@ pub mod mutation_operators {
         BIT_OP_SHIFT_DIR_SWAP = "bit_op_shift_dir_swap";
         BIT_OP_XOR_AND_SWAP = "bit_op_xor_and_swap";
         BOOL_EXPR_NEGATE = "bool_expr_negate";
+        BOOL_LIT_COND_FLIP = "bool_lit_cond_flip";
+        CALL_ARG_SWAP = "call_arg_swap";
         CALL_DELETE = "call_delete";
         CALL_VALUE_DEFAULT_SHADOW = "call_value_default_shadow";
         CONTINUE_BREAK_SWAP = "continue_break_swap";
         EQ_OP_INVERT = "eq_op_invert";
+        INT_LIT_BOUNDARY = "int_lit_boundary";
         LOGICAL_OP_AND_OR_SWAP = "logical_op_and_or_swap";
+        MATCH_GUARD_NEGATE = "match_guard_negate";
         MATH_OP_ADD_MUL_SWAP = "math_op_add_mul_swap";
         MATH_OP_ADD_SUB_SWAP = "math_op_add_sub_swap";
         MATH_OP_DIV_REM_SWAP = "math_op_div_rem_swap";
         MATH_OP_MUL_DIV_SWAP = "math_op_mul_div_swap";
+        METHOD_CALL_SKIP = "method_call_skip";
         RANGE_LIMIT_SWAP = "range_limit_swap";
         RELATIONAL_OP_EQ_SWAP = "relational_op_eq_swap";
         RELATIONAL_OP_INVERT = "relational_op_invert";
+        RETURN_VALUE_DEFAULT = "return_value_default";
+        TRY_OP_REMOVE = "try_op_remove";
     }
 }
 
@@ -73,6 +82,8 @@ pub mod print {
     crate::opts! { ALL, pub(crate) possible_values where
         TESTS = "tests"; ["Print list of test cases."]
         TARGETS = "targets"; ["Print list of functions targeted for mutation at the specified depth."]
+        TARGET_SUMMARY = "target-summary"; ["Print table of functions targeted for mutation, with the number of mutations in them, and the number of entry points they are reachable from."]
+        UNTESTED_FUNCTIONS = "untested-functions"; ["Print list of functions which are not reachable from any test case, grouped by source file."]
         CALL_GRAPH = "call-graph"; ["Print call graph of test cases."]
         CONFLICT_GRAPH = "conflict-graph"; ["Print mutation conflict graph."]
         COMPATIBILITY_GRAPH = "compatibility-graph"; ["Print mutation compatibility graph (i.e. the complement graph of the conflict graph)."]
@@ -140,9 +151,13 @@ pub fn command() -> clap::Command {
         .arg(clap::arg!(--unsafe "Mutate code in `unsafe` blocks.").display_order(114))
         .group(clap::ArgGroup::new("unsafe-targeting").args(&["safe", "cautious", "risky", "unsafe"]).multiple(false))
         .arg(clap::arg!(--"mutation-operators" [MUTATION_OPERATORS] "Mutation operators to apply to the code, separated by commas.").value_delimiter(',').value_parser(mutation_operators::possible_values()).default_value("all").display_order(115))
+        .arg(clap::arg!(--"exclude-mutation-operators" [MUTATION_OPERATORS] "Mutation operators to exclude from the applied mutation operators, separated by commas.").value_delimiter(',').value_parser(mutation_operators::possible_values()).display_order(115))
+        .arg(clap::arg!(--"exclude-paths" [PATHS] "Source paths (files or directories), relative to the package root, to exclude from mutation, separated by commas.").value_delimiter(',').value_parser(clap::value_parser!(PathBuf)).display_order(116))
+        .arg(clap::arg!(--"ignore-file" [IGNORE_FILE] "File, relative to the package root, listing the content hashes (or IDs) of mutations to exclude from the run, one per line. Useful for suppressing known equivalent mutations.").value_parser(clap::value_parser!(PathBuf)).display_order(116))
         .arg(clap::arg!(--"call-graph-depth-limit" [CALL_GRAPH_DEPTH_LIMIT] "Limit depth of call graph analysis, which is complete by default.").value_parser(clap::value_parser!(usize)).display_order(150))
         .arg(clap::arg!(--"call-graph-trace-length-limit" [CALL_GRAPH_TRACE_LENGTH_LIMIT] "Limit maximum length of analyzed call traces during call graph analysis, which is complete by default.").value_parser(clap::value_parser!(usize)).display_order(150))
         .arg(clap::arg!(-d --depth [DEPTH] "Callees of each test function are mutated up to the specified depth.").default_value("3").value_parser(clap::value_parser!(usize)).display_order(150))
+        .arg(clap::arg!(--"max-mutations-per-target" [MAX_MUTATIONS_PER_TARGET] "Limit the number of mutations generated in each function. Mutations of functions exceeding the limit are deterministically sampled.").value_parser(clap::value_parser!(usize).range(1..)).display_order(150))
         .arg(clap::arg!(--"mutant-batch-algorithm" [MUTANT_BATCH_ALGORITHM] "Algorithm to use to optionally batch mutations into parallel groups.").value_parser(mutant_batch_algorithm::possible_values()).default_value(mutant_batch_algorithm::NONE).display_order(199))
         .arg(clap::arg!(--"mutant-batch-size" [MUTANT_BATCH_SIZE] "Maximum number of mutations to batch into a single batch.").default_value("1").value_parser(clap::value_parser!(usize)).display_order(199))
         .arg(clap::arg!(--"mutant-batch-seed" [MUTANT_BATCH_SEED] "Random seed to use for randomness during mutation batching.").display_order(199))
@@ -158,8 +173,10 @@ pub fn command() -> clap::Command {
         .arg(clap::arg!(--"call-graph-filter-entry-points" [ENTRY_POINTS] "Filter entry points to display the call graph for. Multiple may be specified, separated by commas.").value_delimiter(',').display_order(103))
         // Experimental Flags
         .arg(clap::arg!(--"Zwrite-json" [OUT_DIR] "Write JSON metadata files. An output directory may be optionally specified.").num_args(0..=1).require_equals(true).value_parser(clap::value_parser!(PathBuf)).display_order(500))
+        .arg(clap::arg!(--"Zwrite-json-compress" "Write the larger JSON metadata files (call graph, mutations, evaluation stream) specified by `--Zwrite-json` as gzip-compressed `.json.gz` and `.jsonl.gz` files. Compression costs a modest amount of CPU time, but considerably reduces the size of the files.").display_order(500))
         .arg(clap::arg!(--Zverify [VERIFY] "Perform additional checks to verify correctness and completeness. Multiple may be specified, separated by commas.").value_delimiter(',').value_parser(verify::possible_values()).display_order(500))
         .arg(clap::arg!(--Zembedded "Enable experimental support for embedded-test tests and embedded firmware generation with no_std support using a tethered embedded mutation runtime.").display_order(500))
+        .arg(clap::arg!(--"Zprint-spans" [FILE] "Print the raw line, column, and byte offset of each test, definition, call, and mutation span in the specified source file, relative to the package root. Used for debugging span handling.").require_equals(true).value_parser(clap::value_parser!(PathBuf)).display_order(500))
         .arg(clap::arg!(--"Zno-sanitize-macro-expns" "Skip sanitizing the identifiers and paths in the expanded output of macro invocations. This was the previous behavior and is not recommended.").display_order(500))
         // Information
         // FIXME: Regression; the `help` subcommand can no longer be customized, so the about text does not match that
@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Deserializer};
+
+#[derive(Debug)]
+pub enum MutationOperatorWithOptions<T> {
+    EnabledWithDefaults(bool),
+    EnabledWithOptions(T),
+}
+
+impl<'de, T> Deserialize<'de> for MutationOperatorWithOptions<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_untagged::UntaggedEnumVisitor::new()
+            .expecting("a boolean value or a record of options")
+            .bool(|v| Ok(MutationOperatorWithOptions::EnabledWithDefaults(v)))
+            .map(|v| v.deserialize().map(MutationOperatorWithOptions::EnabledWithOptions))
+            .deserialize(deserializer)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CallIgnoreOptions {
+    pub limit_scope_to_local_callees: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MutationOperators {
+    pub arg_default_shadow: Option<bool>,
+    pub bit_op_or_and_swap: Option<bool>,
+    pub bit_op_or_xor_swap: Option<bool>,
+    pub bit_op_shift_dir_swap: Option<bool>,
+    pub bit_op_xor_and_swap: Option<bool>,
+    pub bool_expr_negate: Option<bool>,
+    pub bool_lit_cond_flip: Option<bool>,
+    pub call_arg_swap: Option<bool>,
+    pub call_delete: Option<MutationOperatorWithOptions<CallIgnoreOptions>>,
+    pub call_value_default_shadow: Option<MutationOperatorWithOptions<CallIgnoreOptions>>,
+    pub continue_break_swap: Option<bool>,
+    pub eq_op_invert: Option<bool>,
+    pub int_lit_boundary: Option<bool>,
+    pub logical_op_and_or_swap: Option<bool>,
+    pub match_guard_negate: Option<bool>,
+    pub math_op_add_mul_swap: Option<bool>,
+    pub math_op_add_sub_swap: Option<bool>,
+    pub math_op_div_rem_swap: Option<bool>,
+    pub math_op_mul_div_swap: Option<bool>,
+    pub method_call_skip: Option<bool>,
+    pub range_limit_swap: Option<bool>,
+    pub relational_op_eq_swap: Option<bool>,
+    pub relational_op_invert: Option<bool>,
+    pub return_value_default: Option<bool>,
+    pub try_op_remove: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GreedyMutationBatchingOrderingHeuristic {
+    None,
+    Conflicts,
+    ReverseConflicts,
+    Random,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", rename_all_fields = "kebab-case")]
+#[serde(tag = "batching-algorithm")]
+pub enum MutationBatchingAlgorithm {
+    Random,
+    Greedy {
+        greedy_batching_ordering_heuristic: Option<GreedyMutationBatchingOrderingHeuristic>,
+        greedy_batching_epsilon: Option<f64>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", rename_all_fields = "kebab-case")]
+#[serde(tag = "mode")]
+pub enum MutationParallelism {
+    None,
+    Batching {
+        batch_size: Option<usize>,
+        #[serde(flatten)]
+        batching_algorithm: Option<MutationBatchingAlgorithm>,
+        batching_seed: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MutationIsolation {
+    Unsafe,
+    All,
+}
+
+/// Options specified in the `package.metadata.mutest` and `workspace.metadata.mutest` tables of Cargo manifests.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CargoMutestMetadata {
+    pub call_graph_depth_limit: Option<usize>,
+    pub call_graph_trace_length_limit: Option<usize>,
+    pub exclude_mutation_operators: Option<Vec<String>>,
+    pub exclude_paths: Option<Vec<PathBuf>>,
+    pub mutation_depth: Option<usize>,
+    pub mutation_operators: Option<MutationOperators>,
+    pub mutation_parallelism: Option<MutationParallelism>,
+
+    // Options of `cargo mutest run`.
+    pub isolate: Option<MutationIsolation>,
+    pub use_thread_pool: Option<bool>,
+    /// Timeout for each test run against a mutation, in seconds.
+    pub test_timeout: Option<f64>,
+}
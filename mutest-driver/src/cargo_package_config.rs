@@ -1,195 +1,142 @@
 use std::env;
+use std::path::{Path, PathBuf};
 
+use mutest_driver_cli::package_config::{CargoMutestMetadata, MutationOperatorWithOptions, MutationOperators};
 use mutest_emit::codegen::mutation::OperatorRef;
 use rustc_session::EarlyDiagCtxt;
-use serde::{Deserialize, Deserializer};
 
-#[derive(Debug)]
-enum MutationOperatorWithOptions<T> {
-    EnabledWithDefaults(bool),
-    EnabledWithOptions(T),
-}
-
-impl<'de, T> Deserialize<'de> for MutationOperatorWithOptions<T>
-where
-    T: Deserialize<'de>,
-{
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        serde_untagged::UntaggedEnumVisitor::new()
-            .expecting("a boolean value or a record of options")
-            .bool(|v| Ok(MutationOperatorWithOptions::EnabledWithDefaults(v)))
-            .map(|v| v.deserialize().map(MutationOperatorWithOptions::EnabledWithOptions))
-            .deserialize(deserializer)
-    }
-}
-
-#[derive(Debug, Default, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-struct CallIgnoreOptions {
-    limit_scope_to_local_callees: bool,
-}
+pub use mutest_driver_cli::package_config::{GreedyMutationBatchingOrderingHeuristic, MutationBatchingAlgorithm, MutationParallelism};
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-struct MutationOperators {
-    arg_default_shadow: Option<bool>,
-    bit_op_or_and_swap: Option<bool>,
-    bit_op_or_xor_swap: Option<bool>,
-    bit_op_shift_dir_swap: Option<bool>,
-    bit_op_xor_and_swap: Option<bool>,
-    bool_expr_negate: Option<bool>,
-    call_delete: Option<MutationOperatorWithOptions<CallIgnoreOptions>>,
-    call_value_default_shadow: Option<MutationOperatorWithOptions<CallIgnoreOptions>>,
-    continue_break_swap: Option<bool>,
-    eq_op_invert: Option<bool>,
-    logical_op_and_or_swap: Option<bool>,
-    math_op_add_mul_swap: Option<bool>,
-    math_op_add_sub_swap: Option<bool>,
-    math_op_div_rem_swap: Option<bool>,
-    math_op_mul_div_swap: Option<bool>,
-    range_limit_swap: Option<bool>,
-    relational_op_eq_swap: Option<bool>,
-    relational_op_invert: Option<bool>,
-}
+fn enabled_mutation_operators<'op, 'm>(mutation_operators: &MutationOperators) -> Vec<(&'static str, OperatorRef<'op, 'm>)> {
+    use mutest_driver_cli::mutation_operators as opts;
 
-impl MutationOperators {
-    fn into_operators<'op, 'm>(&self) -> Vec<OperatorRef<'op, 'm>> {
-        // NOTE: Mutation operators must be sorted into a deterministic order,
-        //       because that determines application order, and thus mutation order.
-        //       We use an alphabetical order for this based on operator names,
-        //       so pushes must be sorted accordingly.
-        let mut ops = Vec::<OperatorRef<'op, 'm>>::new();
+    // NOTE: Mutation operators must be sorted into a deterministic order,
+    //       because that determines application order, and thus mutation order.
+    //       We use an alphabetical order for this based on operator names,
+    //       so pushes must be sorted accordingly.
+    let mut ops = Vec::<(&'static str, OperatorRef<'op, 'm>)>::new();
 
-        if let Some(true) = &self.arg_default_shadow {
-            ops.push(Box::leak(Box::new(mutest_operators::ArgDefaultShadow)));
-        }
-        if let Some(true) = &self.bit_op_or_and_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::BitOpOrAndSwap)))
-        }
-        if let Some(true) = &self.bit_op_or_xor_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::BitOpOrXorSwap)))
-        }
-        if let Some(true) = &self.bit_op_shift_dir_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::BitOpShiftDirSwap)))
-        }
-        if let Some(true) = &self.bit_op_xor_and_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::BitOpXorAndSwap)))
-        }
-        if let Some(true) = &self.bool_expr_negate {
-            ops.push(Box::leak(Box::new(mutest_operators::BoolExprNegate)))
-        }
-        if let Some(call_ignore_opts) = &self.call_delete {
-            'v: {
-                let call_ignore_opts = match call_ignore_opts {
-                    MutationOperatorWithOptions::EnabledWithDefaults(false) => break 'v,
-                    MutationOperatorWithOptions::EnabledWithDefaults(true) => &Default::default(),
-                    MutationOperatorWithOptions::EnabledWithOptions(opts) => opts,
-                };
+    if let Some(true) = &mutation_operators.arg_default_shadow {
+        ops.push((opts::ARG_DEFAULT_SHADOW, Box::leak(Box::new(mutest_operators::ArgDefaultShadow))));
+    }
+    if let Some(true) = &mutation_operators.bit_op_or_and_swap {
+        ops.push((opts::BIT_OP_OR_AND_SWAP, Box::leak(Box::new(mutest_operators::BitOpOrAndSwap))));
+    }
+    if let Some(true) = &mutation_operators.bit_op_or_xor_swap {
+        ops.push((opts::BIT_OP_OR_XOR_SWAP, Box::leak(Box::new(mutest_operators::BitOpOrXorSwap))));
+    }
+    if let Some(true) = &mutation_operators.bit_op_shift_dir_swap {
+        ops.push((opts::BIT_OP_SHIFT_DIR_SWAP, Box::leak(Box::new(mutest_operators::BitOpShiftDirSwap))));
+    }
+    if let Some(true) = &mutation_operators.bit_op_xor_and_swap {
+        ops.push((opts::BIT_OP_XOR_AND_SWAP, Box::leak(Box::new(mutest_operators::BitOpXorAndSwap))));
+    }
+    if let Some(true) = &mutation_operators.bool_expr_negate {
+        ops.push((opts::BOOL_EXPR_NEGATE, Box::leak(Box::new(mutest_operators::BoolExprNegate))));
+    }
+    if let Some(true) = &mutation_operators.bool_lit_cond_flip {
+        ops.push((opts::BOOL_LIT_COND_FLIP, Box::leak(Box::new(mutest_operators::BoolLitCondFlip))));
+    }
+    if let Some(true) = &mutation_operators.call_arg_swap {
+        ops.push((opts::CALL_ARG_SWAP, Box::leak(Box::new(mutest_operators::CallArgSwap))));
+    }
+    if let Some(call_ignore_opts) = &mutation_operators.call_delete {
+        'v: {
+            let call_ignore_opts = match call_ignore_opts {
+                MutationOperatorWithOptions::EnabledWithDefaults(false) => break 'v,
+                MutationOperatorWithOptions::EnabledWithDefaults(true) => &Default::default(),
+                MutationOperatorWithOptions::EnabledWithOptions(opts) => opts,
+            };
 
-                ops.push(Box::leak(Box::new(mutest_operators::CallDelete {
-                    limit_scope_to_local_callees: call_ignore_opts.limit_scope_to_local_callees,
-                })));
-            }
+            ops.push((opts::CALL_DELETE, Box::leak(Box::new(mutest_operators::CallDelete {
+                limit_scope_to_local_callees: call_ignore_opts.limit_scope_to_local_callees,
+            }))));
         }
-        if let Some(call_ignore_opts) = &self.call_value_default_shadow {
-            'v: {
-                let call_ignore_opts = match call_ignore_opts {
-                    MutationOperatorWithOptions::EnabledWithDefaults(false) => break 'v,
-                    MutationOperatorWithOptions::EnabledWithDefaults(true) => &Default::default(),
-                    MutationOperatorWithOptions::EnabledWithOptions(opts) => opts,
-                };
+    }
+    if let Some(call_ignore_opts) = &mutation_operators.call_value_default_shadow {
+        'v: {
+            let call_ignore_opts = match call_ignore_opts {
+                MutationOperatorWithOptions::EnabledWithDefaults(false) => break 'v,
+                MutationOperatorWithOptions::EnabledWithDefaults(true) => &Default::default(),
+                MutationOperatorWithOptions::EnabledWithOptions(opts) => opts,
+            };
 
-                ops.push(Box::leak(Box::new(mutest_operators::CallValueDefaultShadow {
-                    limit_scope_to_local_callees: call_ignore_opts.limit_scope_to_local_callees,
-                })));
-            }
-        }
-        if let Some(true) = &self.continue_break_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::ContinueBreakSwap)))
-        }
-        if let Some(true) = &self.eq_op_invert {
-            ops.push(Box::leak(Box::new(mutest_operators::EqOpInvert)))
-        }
-        if let Some(true) = &self.logical_op_and_or_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::LogicalOpAndOrSwap)))
-        }
-        if let Some(true) = &self.math_op_add_mul_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::OpAddMulSwap)))
-        }
-        if let Some(true) = &self.math_op_add_sub_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::OpAddSubSwap)))
+            ops.push((opts::CALL_VALUE_DEFAULT_SHADOW, Box::leak(Box::new(mutest_operators::CallValueDefaultShadow {
+                limit_scope_to_local_callees: call_ignore_opts.limit_scope_to_local_callees,
+            }))));
         }
-        if let Some(true) = &self.math_op_div_rem_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::OpDivRemSwap)))
-        }
-        if let Some(true) = &self.math_op_mul_div_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::OpMulDivSwap)))
-        }
-        if let Some(true) = &self.range_limit_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::RangeLimitSwap)))
-        }
-        if let Some(true) = &self.relational_op_eq_swap {
-            ops.push(Box::leak(Box::new(mutest_operators::RelationalOpEqSwap)))
-        }
-        if let Some(true) = &self.relational_op_invert {
-            ops.push(Box::leak(Box::new(mutest_operators::RelationalOpInvert)))
-        }
-
-        ops
     }
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum GreedyMutationBatchingOrderingHeuristic {
-    None,
-    Conflicts,
-    ReverseConflicts,
-    Random,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case", rename_all_fields = "kebab-case")]
-#[serde(tag = "batching-algorithm")]
-pub enum MutationBatchingAlgorithm {
-    Random,
-    Greedy {
-        greedy_batching_ordering_heuristic: Option<GreedyMutationBatchingOrderingHeuristic>,
-        greedy_batching_epsilon: Option<f64>,
-    },
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case", rename_all_fields = "kebab-case")]
-#[serde(tag = "mode")]
-pub enum MutationParallelism {
-    None,
-    Batching {
-        batch_size: Option<usize>,
-        #[serde(flatten)]
-        batching_algorithm: Option<MutationBatchingAlgorithm>,
-        batching_seed: Option<String>,
-    },
-}
+    if let Some(true) = &mutation_operators.continue_break_swap {
+        ops.push((opts::CONTINUE_BREAK_SWAP, Box::leak(Box::new(mutest_operators::ContinueBreakSwap))));
+    }
+    if let Some(true) = &mutation_operators.eq_op_invert {
+        ops.push((opts::EQ_OP_INVERT, Box::leak(Box::new(mutest_operators::EqOpInvert))));
+    }
+    if let Some(true) = &mutation_operators.int_lit_boundary {
+        ops.push((opts::INT_LIT_BOUNDARY, Box::leak(Box::new(mutest_operators::IntLitBoundary))));
+    }
+    if let Some(true) = &mutation_operators.logical_op_and_or_swap {
+        ops.push((opts::LOGICAL_OP_AND_OR_SWAP, Box::leak(Box::new(mutest_operators::LogicalOpAndOrSwap))));
+    }
+    if let Some(true) = &mutation_operators.match_guard_negate {
+        ops.push((opts::MATCH_GUARD_NEGATE, Box::leak(Box::new(mutest_operators::MatchGuardNegate))));
+    }
+    if let Some(true) = &mutation_operators.math_op_add_mul_swap {
+        ops.push((opts::MATH_OP_ADD_MUL_SWAP, Box::leak(Box::new(mutest_operators::OpAddMulSwap))));
+    }
+    if let Some(true) = &mutation_operators.math_op_add_sub_swap {
+        ops.push((opts::MATH_OP_ADD_SUB_SWAP, Box::leak(Box::new(mutest_operators::OpAddSubSwap))));
+    }
+    if let Some(true) = &mutation_operators.math_op_div_rem_swap {
+        ops.push((opts::MATH_OP_DIV_REM_SWAP, Box::leak(Box::new(mutest_operators::OpDivRemSwap))));
+    }
+    if let Some(true) = &mutation_operators.math_op_mul_div_swap {
+        ops.push((opts::MATH_OP_MUL_DIV_SWAP, Box::leak(Box::new(mutest_operators::OpMulDivSwap))));
+    }
+    if let Some(true) = &mutation_operators.method_call_skip {
+        ops.push((opts::METHOD_CALL_SKIP, Box::leak(Box::new(mutest_operators::MethodCallSkip))));
+    }
+    if let Some(true) = &mutation_operators.range_limit_swap {
+        ops.push((opts::RANGE_LIMIT_SWAP, Box::leak(Box::new(mutest_operators::RangeLimitSwap))));
+    }
+    if let Some(true) = &mutation_operators.relational_op_eq_swap {
+        ops.push((opts::RELATIONAL_OP_EQ_SWAP, Box::leak(Box::new(mutest_operators::RelationalOpEqSwap))));
+    }
+    if let Some(true) = &mutation_operators.relational_op_invert {
+        ops.push((opts::RELATIONAL_OP_INVERT, Box::leak(Box::new(mutest_operators::RelationalOpInvert))));
+    }
+    if let Some(true) = &mutation_operators.return_value_default {
+        ops.push((opts::RETURN_VALUE_DEFAULT, Box::leak(Box::new(mutest_operators::ReturnValueDefault))));
+    }
+    if let Some(true) = &mutation_operators.try_op_remove {
+        ops.push((opts::TRY_OP_REMOVE, Box::leak(Box::new(mutest_operators::TryOpRemove))));
+    }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-struct CargoMutestMetadata {
-    call_graph_depth_limit: Option<usize>,
-    call_graph_trace_length_limit: Option<usize>,
-    mutation_depth: Option<usize>,
-    mutation_operators: Option<MutationOperators>,
-    mutation_parallelism: Option<MutationParallelism>,
+    ops
 }
 
 pub struct MergedCargoPackageConfig<'op, 'm> {
     pub call_graph_depth_limit: Option<usize>,
     pub call_graph_trace_length_limit: Option<usize>,
+    /// Excluded mutation operators, converted from the kebab-case names used in Cargo manifests.
+    pub exclude_mutation_operators: Option<Vec<String>>,
+    /// Excluded paths, resolved relative to the directory of the manifest they were specified in.
+    pub exclude_paths: Option<Vec<PathBuf>>,
     pub mutation_depth: Option<usize>,
-    pub mutation_operators: Option<Vec<OperatorRef<'op, 'm>>>,
+    /// Enabled mutation operators, along with their names.
+    pub mutation_operators: Option<Vec<(&'static str, OperatorRef<'op, 'm>)>>,
     pub mutation_parallelism: Option<MutationParallelism>,
 }
 
+fn mutation_operator_from_manifest_name(manifest_op_name: &str) -> Option<&'static str> {
+    mutest_driver_cli::mutation_operators::ALL.iter().copied()
+        .find(|op_name| op_name.replace('_', "-") == manifest_op_name)
+}
+
+fn resolve_exclude_paths(exclude_paths: &[PathBuf], manifest_dir: &Path) -> Vec<PathBuf> {
+    exclude_paths.iter().map(|exclude_path| manifest_dir.join(exclude_path)).collect()
+}
+
 pub fn fetch_merged_cargo_package_config<'op, 'm>(early_dcx: &EarlyDiagCtxt) -> Option<MergedCargoPackageConfig<'op, 'm>> {
     let cargo_package_manifest_path_str = env::var("CARGO_MANIFEST_PATH").ok()?;
 
@@ -237,16 +184,24 @@ pub fn fetch_merged_cargo_package_config<'op, 'm>(early_dcx: &EarlyDiagCtxt) ->
 
     if package_mutest_metadata.is_none() && workspace_mutest_metadata.is_none() { return None; }
 
-    let merged_cargo_package_config = MergedCargoPackageConfig {
+    let mut merged_cargo_package_config = MergedCargoPackageConfig {
         call_graph_depth_limit: package_mutest_metadata.as_ref().and_then(|m| m.call_graph_depth_limit)
             .or_else(|| workspace_mutest_metadata.as_ref().and_then(|m| m.call_graph_depth_limit)),
         call_graph_trace_length_limit: package_mutest_metadata.as_ref().and_then(|m| m.call_graph_trace_length_limit)
             .or_else(|| workspace_mutest_metadata.as_ref().and_then(|m| m.call_graph_trace_length_limit)),
+        exclude_mutation_operators: package_mutest_metadata.as_mut().and_then(|m| m.exclude_mutation_operators.take())
+            .or_else(|| workspace_mutest_metadata.as_mut().and_then(|m| m.exclude_mutation_operators.take())),
+        exclude_paths: package_mutest_metadata.as_ref().and_then(|m| m.exclude_paths.as_ref())
+            .map(|exclude_paths| resolve_exclude_paths(exclude_paths, package.manifest_path.parent().unwrap().as_std_path()))
+            .or_else(|| {
+                workspace_mutest_metadata.as_ref().and_then(|m| m.exclude_paths.as_ref())
+                    .map(|exclude_paths| resolve_exclude_paths(exclude_paths, metadata.workspace_root.as_std_path()))
+            }),
         mutation_depth: package_mutest_metadata.as_ref().and_then(|m| m.mutation_depth)
             .or_else(|| workspace_mutest_metadata.as_ref().and_then(|m| m.mutation_depth)),
         mutation_operators: package_mutest_metadata.as_ref().and_then(|m| m.mutation_operators.as_ref())
             .or_else(|| workspace_mutest_metadata.as_ref().and_then(|m| m.mutation_operators.as_ref()))
-            .map(|mutation_operators| mutation_operators.into_operators()),
+            .map(enabled_mutation_operators),
         mutation_parallelism: package_mutest_metadata.as_mut().and_then(|m| m.mutation_parallelism.take())
             .or_else(|| workspace_mutest_metadata.as_mut().and_then(|m| m.mutation_parallelism.take())),
     };
@@ -257,5 +212,51 @@ pub fn fetch_merged_cargo_package_config<'op, 'm>(early_dcx: &EarlyDiagCtxt) ->
         diagnostic.emit();
     }
 
+    if let Some(exclude_mutation_operators) = &mut merged_cargo_package_config.exclude_mutation_operators {
+        if merged_cargo_package_config.mutation_operators.is_some() && !exclude_mutation_operators.is_empty() {
+            let mut diagnostic = early_dcx.early_struct_fatal("cannot both enable and exclude mutation operators in Cargo package manifest");
+            diagnostic.note("consider disabling the excluded mutation operators in the `mutation-operators` table instead");
+            diagnostic.emit();
+        }
+
+        for op_name in exclude_mutation_operators {
+            // NOTE: Mutation operators are named in kebab-case in the Cargo manifest, like the keys of the `mutation-operators` table.
+            match mutation_operator_from_manifest_name(op_name) {
+                Some(internal_op_name) => *op_name = internal_op_name.to_owned(),
+                None => {
+                    let mut diagnostic = early_dcx.early_struct_fatal(format!("unknown mutation operator `{op_name}` in `exclude-mutation-operators`"));
+                    diagnostic.note("mutation operators are named in kebab-case in Cargo manifests, e.g. `call-delete`");
+                    diagnostic.note("while parsing `metadata.mutest` in Cargo manifest");
+                    diagnostic.emit();
+                }
+            }
+        }
+    }
+
     Some(merged_cargo_package_config)
 }
+
+#[test]
+fn test_deserialize_cargo_mutest_metadata_exclusions() {
+    let mutest_metadata = serde_json::json!({
+        "mutation-depth": 2,
+        "exclude-mutation-operators": ["call-delete", "int-lit-boundary"],
+        "exclude-paths": ["src/generated", "src/ffi.rs"],
+    });
+
+    let mutest_metadata = serde_path_to_error::deserialize::<_, CargoMutestMetadata>(&mutest_metadata).unwrap();
+    assert_eq!(Some(2), mutest_metadata.mutation_depth);
+    assert_eq!(Some(vec!["call-delete".to_owned(), "int-lit-boundary".to_owned()]), mutest_metadata.exclude_mutation_operators);
+    assert_eq!(Some(vec![PathBuf::from("src/generated"), PathBuf::from("src/ffi.rs")]), mutest_metadata.exclude_paths);
+
+    let exclude_paths = resolve_exclude_paths(&mutest_metadata.exclude_paths.unwrap(), Path::new("/crates/foo"));
+    assert_eq!(vec![PathBuf::from("/crates/foo/src/generated"), PathBuf::from("/crates/foo/src/ffi.rs")], exclude_paths);
+}
+
+#[test]
+fn test_mutation_operator_from_manifest_name() {
+    assert_eq!(Some("call_delete"), mutation_operator_from_manifest_name("call-delete"));
+    assert_eq!(Some("math_op_add_sub_swap"), mutation_operator_from_manifest_name("math-op-add-sub-swap"));
+    assert_eq!(None, mutation_operator_from_manifest_name("call_delete"));
+    assert_eq!(None, mutation_operator_from_manifest_name("unknown-op"));
+}
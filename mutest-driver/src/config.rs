@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use mutest_emit::codegen::mutation::{Operators, UnsafeTargeting};
+use rustc_hash::FxHashSet;
 use rustc_interface::Config as CompilerConfig;
 
 use crate::passes::external_mutant::ExternalTargets;
@@ -84,10 +85,14 @@ pub struct PrintOptions {
     pub print_headers: bool,
     pub tests: Option<()>,
     pub mutation_targets: Option<()>,
+    pub target_summary: Option<()>,
+    pub untested_fns: Option<()>,
     pub call_graph: Option<CallGraphOptions>,
     pub conflict_graph: Option<ConflictGraphOptions>,
     pub mutations: Option<()>,
     pub code: Option<()>,
+    /// Source file to print the raw spans of.
+    pub spans: Option<PathBuf>,
 }
 
 impl PrintOptions {
@@ -95,16 +100,20 @@ impl PrintOptions {
         true
             && self.tests.is_none()
             && self.mutation_targets.is_none()
+            && self.target_summary.is_none()
+            && self.untested_fns.is_none()
             && self.call_graph.is_none()
             && self.conflict_graph.is_none()
             && self.mutations.is_none()
             && self.code.is_none()
+            && self.spans.is_none()
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct WriteOptions {
     pub out_dir: PathBuf,
+    pub compress: bool,
 }
 
 pub enum Mode {
@@ -156,6 +165,41 @@ pub struct VerifyOptions {
     pub ast_lowering: bool,
 }
 
+/// Mutations excluded from the run, e.g. because they were triaged as equivalent mutations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IgnoredMutations {
+    /// Content hashes of ignored mutations, as written into the mutations JSON file.
+    pub content_hashes: FxHashSet<String>,
+    /// IDs of ignored mutations. Note that, unlike content hashes, mutation IDs are not stable across code changes.
+    pub mutation_ids: FxHashSet<u32>,
+}
+
+impl IgnoredMutations {
+    /// Parses the contents of an ignore file, which lists mutation content hashes, or mutation IDs, one per line.
+    /// Empty lines, and comments starting with `#` are ignored.
+    pub fn parse(ignore_file_str: &str) -> Result<Self, String> {
+        let mut ignored_mutations = Self::default();
+
+        for (line_idx, line) in ignore_file_str.lines().enumerate() {
+            let entry = line.split_once('#').map(|(entry, _comment)| entry).unwrap_or(line).trim();
+            if entry.is_empty() { continue; }
+
+            if entry.bytes().all(|b| b.is_ascii_digit()) {
+                match entry.parse::<u32>() {
+                    Ok(mutation_id) if mutation_id >= 1 => { ignored_mutations.mutation_ids.insert(mutation_id); }
+                    _ => return Err(format!("invalid mutation id `{entry}` on line {line}", line = line_idx + 1)),
+                }
+            } else if entry.len() == 16 && entry.bytes().all(|b| b.is_ascii_hexdigit()) {
+                ignored_mutations.content_hashes.insert(entry.to_ascii_lowercase());
+            } else {
+                return Err(format!("invalid mutation content hash `{entry}` on line {line}", line = line_idx + 1));
+            }
+        }
+
+        Ok(ignored_mutations)
+    }
+}
+
 pub struct Options<'op, 'm> {
     pub crate_kind: CrateKind,
     pub cargo_target_kind: Option<CargoTargetKind>,
@@ -166,9 +210,13 @@ pub struct Options<'op, 'm> {
     pub print_opts: PrintOptions,
     pub unsafe_targeting: UnsafeTargeting,
     pub operators: Operators<'op, 'm>,
+    /// Source files, and directories of source files, which are not mutated.
+    pub exclude_paths: Vec<PathBuf>,
     pub call_graph_depth_limit: Option<usize>,
     pub call_graph_trace_length_limit: Option<usize>,
     pub mutation_depth: usize,
+    pub max_mutations_per_target: Option<usize>,
+    pub ignored_mutations: Option<IgnoredMutations>,
     pub mutation_parallelism: Option<MutationParallelism>,
 
     pub write_opts: Option<WriteOptions>,
@@ -190,3 +238,13 @@ impl<'op, 'm> Config<'op, 'm> {
         self.mutest_target_dir_root.clone().unwrap_or(self.compiler_config.output_dir.clone().unwrap_or_default())
     }
 }
+
+#[test]
+fn test_parse_ignored_mutations() {
+    let ignored_mutations = IgnoredMutations::parse("# equivalent mutations\n92c92add98bca27f\n\n  12 # flaky\n985AEAA27A5ADE35\n").unwrap();
+    assert_eq!(FxHashSet::from_iter(["92c92add98bca27f".to_owned(), "985aeaa27a5ade35".to_owned()]), ignored_mutations.content_hashes);
+    assert_eq!(FxHashSet::from_iter([12]), ignored_mutations.mutation_ids);
+
+    assert!(IgnoredMutations::parse("0").is_err());
+    assert!(IgnoredMutations::parse("92c92add").is_err());
+}
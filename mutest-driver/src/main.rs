@@ -284,14 +284,22 @@ pub fn main() {
             let mut print_names = mutest_arg_matches.get_many::<String>("print").map(|print| print.map(String::as_str).collect::<FxHashSet<_>>()).unwrap_or_default();
             if print_names.contains("all") { print_names = FxHashSet::from_iter(opts::ALL.into_iter().map(|s| *s)); }
 
+            let spans = mutest_arg_matches.get_one::<PathBuf>("Zprint-spans").map(|file_path| {
+                let package_root_dir = env::var("CARGO_MANIFEST_DIR").map(PathBuf::from).unwrap_or_default();
+                package_root_dir.join(file_path)
+            });
+
             let mut print_opts = config::PrintOptions {
-                print_headers: print_names.len() > 1,
+                print_headers: print_names.len() + spans.iter().len() > 1,
                 tests: None,
                 mutation_targets: None,
+                target_summary: None,
+                untested_fns: None,
                 call_graph: None,
                 conflict_graph: None,
                 mutations: None,
                 code: None,
+                spans,
             };
 
             let graph_format = {
@@ -308,6 +316,8 @@ pub fn main() {
                 match print_name {
                     opts::TESTS => print_opts.tests = Some(()),
                     opts::TARGETS => print_opts.mutation_targets = Some(()),
+                    opts::TARGET_SUMMARY => print_opts.target_summary = Some(()),
+                    opts::UNTESTED_FUNCTIONS => print_opts.untested_fns = Some(()),
                     opts::CALL_GRAPH => {
                         let entry_point_filters = mutest_arg_matches.get_many::<String>("call-graph-filter-entry-points").map(|s| s.map(|f| f.trim().to_owned()).collect::<Vec<_>>()).unwrap_or_default();
                         let non_local_call_view = {
@@ -342,50 +352,80 @@ pub fn main() {
             _ => UnsafeTargeting::None,
         };
 
-        let mutation_operators = 'mutation_operators: {
+        let mutation_operators = {
             use mutest_driver_cli::mutation_operators as opts;
 
-            if let None | Some(clap::parser::ValueSource::DefaultValue) = mutest_arg_matches.value_source("mutation-operators") {
-                if let Some(c) = &mut package_config && let Some(mutation_operators) = c.mutation_operators.take() {
-                    break 'mutation_operators mutation_operators;
+            let mutation_operators_from_cli = !matches!(mutest_arg_matches.value_source("mutation-operators"), None | Some(clap::parser::ValueSource::DefaultValue));
+
+            let mut ops = match package_config.as_mut().and_then(|c| c.mutation_operators.take()) {
+                Some(ops) if !mutation_operators_from_cli => ops,
+                _ => {
+                    let mut op_names = mutest_arg_matches.get_many::<String>("mutation-operators").unwrap().map(String::as_str).collect::<Vec<_>>();
+                    if op_names.contains(&"all") { op_names = opts::ALL.into_iter().map(|s| *s).collect::<Vec<_>>(); }
+
+                    op_names.into_iter()
+                        .map(|op_name| {
+                            macro const_op_ref($m:expr) { { const OP: OperatorRef<'_, '_> = &$m; OP } }
+
+                            let op = match op_name {
+                                opts::ARG_DEFAULT_SHADOW => const_op_ref!(mutest_operators::ArgDefaultShadow),
+                                opts::BIT_OP_OR_AND_SWAP => const_op_ref!(mutest_operators::BitOpOrAndSwap),
+                                opts::BIT_OP_OR_XOR_SWAP => const_op_ref!(mutest_operators::BitOpOrXorSwap),
+                                opts::BIT_OP_SHIFT_DIR_SWAP => const_op_ref!(mutest_operators::BitOpShiftDirSwap),
+                                opts::BIT_OP_XOR_AND_SWAP => const_op_ref!(mutest_operators::BitOpXorAndSwap),
+                                opts::BOOL_EXPR_NEGATE => const_op_ref!(mutest_operators::BoolExprNegate),
+                                opts::BOOL_LIT_COND_FLIP => const_op_ref!(mutest_operators::BoolLitCondFlip),
+                                opts::CALL_ARG_SWAP => const_op_ref!(mutest_operators::CallArgSwap),
+                                opts::CALL_DELETE => const_op_ref!(mutest_operators::CallDelete { limit_scope_to_local_callees: false }),
+                                opts::CALL_VALUE_DEFAULT_SHADOW => const_op_ref!(mutest_operators::CallValueDefaultShadow { limit_scope_to_local_callees: false }),
+                                opts::CONTINUE_BREAK_SWAP => const_op_ref!(mutest_operators::ContinueBreakSwap),
+                                opts::EQ_OP_INVERT => const_op_ref!(mutest_operators::EqOpInvert),
+                                opts::INT_LIT_BOUNDARY => const_op_ref!(mutest_operators::IntLitBoundary),
+                                opts::LOGICAL_OP_AND_OR_SWAP => const_op_ref!(mutest_operators::LogicalOpAndOrSwap),
+                                opts::MATCH_GUARD_NEGATE => const_op_ref!(mutest_operators::MatchGuardNegate),
+                                opts::MATH_OP_ADD_MUL_SWAP => const_op_ref!(mutest_operators::OpAddMulSwap),
+                                opts::MATH_OP_ADD_SUB_SWAP => const_op_ref!(mutest_operators::OpAddSubSwap),
+                                opts::MATH_OP_DIV_REM_SWAP => const_op_ref!(mutest_operators::OpDivRemSwap),
+                                opts::MATH_OP_MUL_DIV_SWAP => const_op_ref!(mutest_operators::OpMulDivSwap),
+                                opts::METHOD_CALL_SKIP => const_op_ref!(mutest_operators::MethodCallSkip),
+                                opts::RANGE_LIMIT_SWAP => const_op_ref!(mutest_operators::RangeLimitSwap),
+                                opts::RELATIONAL_OP_EQ_SWAP => const_op_ref!(mutest_operators::RelationalOpEqSwap),
+                                opts::RELATIONAL_OP_INVERT => const_op_ref!(mutest_operators::RelationalOpInvert),
+                                opts::RETURN_VALUE_DEFAULT => const_op_ref!(mutest_operators::ReturnValueDefault),
+                                opts::TRY_OP_REMOVE => const_op_ref!(mutest_operators::TryOpRemove),
+                                _ => unreachable!("invalid mutation operator name: `{op_name}`"),
+                            };
+                            (op_name, op)
+                        })
+                        .collect::<Vec<_>>()
+                }
+            };
+
+            // NOTE: Exclusions are applied to the resolved set of mutation operators, regardless of whether it was
+            //       specified on the command line, in the Cargo package manifest, or is the default set.
+            let exclude_op_names = match mutest_arg_matches.get_many::<String>("exclude-mutation-operators") {
+                Some(exclude_op_names) => exclude_op_names.map(String::as_str).collect::<Vec<_>>(),
+                None if let Some(c) = &package_config && let Some(exclude_op_names) = &c.exclude_mutation_operators => {
+                    exclude_op_names.iter().map(String::as_str).collect::<Vec<_>>()
                 }
+                None => vec![],
+            };
+            if exclude_op_names.contains(&"all") { ops.clear(); }
+            ops.retain(|(op_name, _)| !exclude_op_names.contains(op_name));
+
+            if ops.is_empty() {
+                let mut diagnostic = early_dcx.early_struct_fatal("all mutation operators are excluded");
+                diagnostic.note("consider removing some mutation operators from the excluded mutation operators");
+                diagnostic.emit();
             }
 
-            let mut op_names = mutest_arg_matches.get_many::<String>("mutation-operators").unwrap().map(String::as_str).collect::<Vec<_>>();
-            if op_names.contains(&"all") { op_names = opts::ALL.into_iter().map(|s| *s).collect::<Vec<_>>(); }
             // NOTE: Mutation operators must be sorted into a deterministic order,
             //       because that determines application order, and thus mutation order.
             //       We use an alphabetical order for this based on operator names.
-            op_names.sort();
-            op_names.dedup();
-
-            op_names.into_iter()
-                .map(|op_name| {
-                    macro const_op_ref($m:expr) { { const OP: OperatorRef<'_, '_> = &$m; OP } }
-
-                    match op_name {
-                        opts::ARG_DEFAULT_SHADOW => const_op_ref!(mutest_operators::ArgDefaultShadow),
-                        opts::BIT_OP_OR_AND_SWAP => const_op_ref!(mutest_operators::BitOpOrAndSwap),
-                        opts::BIT_OP_OR_XOR_SWAP => const_op_ref!(mutest_operators::BitOpOrXorSwap),
-                        opts::BIT_OP_SHIFT_DIR_SWAP => const_op_ref!(mutest_operators::BitOpShiftDirSwap),
-                        opts::BIT_OP_XOR_AND_SWAP => const_op_ref!(mutest_operators::BitOpXorAndSwap),
-                        opts::BOOL_EXPR_NEGATE => const_op_ref!(mutest_operators::BoolExprNegate),
-                        opts::CALL_DELETE => const_op_ref!(mutest_operators::CallDelete { limit_scope_to_local_callees: false }),
-                        opts::CALL_VALUE_DEFAULT_SHADOW => const_op_ref!(mutest_operators::CallValueDefaultShadow { limit_scope_to_local_callees: false }),
-                        opts::CONTINUE_BREAK_SWAP => const_op_ref!(mutest_operators::ContinueBreakSwap),
-                        opts::EQ_OP_INVERT => const_op_ref!(mutest_operators::EqOpInvert),
-                        opts::LOGICAL_OP_AND_OR_SWAP => const_op_ref!(mutest_operators::LogicalOpAndOrSwap),
-                        opts::MATH_OP_ADD_MUL_SWAP => const_op_ref!(mutest_operators::OpAddMulSwap),
-                        opts::MATH_OP_ADD_SUB_SWAP => const_op_ref!(mutest_operators::OpAddSubSwap),
-                        opts::MATH_OP_DIV_REM_SWAP => const_op_ref!(mutest_operators::OpDivRemSwap),
-                        opts::MATH_OP_MUL_DIV_SWAP => const_op_ref!(mutest_operators::OpMulDivSwap),
-                        opts::RANGE_LIMIT_SWAP => const_op_ref!(mutest_operators::RangeLimitSwap),
-                        opts::RELATIONAL_OP_EQ_SWAP => const_op_ref!(mutest_operators::RelationalOpEqSwap),
-                        opts::RELATIONAL_OP_INVERT => const_op_ref!(mutest_operators::RelationalOpInvert),
-                        _ => unreachable!("invalid mutation operator name: `{op_name}`"),
-                    }
-                })
-                .collect::<Vec<_>>()
+            ops.sort_by_key(|(op_name, _)| *op_name);
+            ops.dedup_by_key(|(op_name, _)| *op_name);
+
+            ops.into_iter().map(|(_, op)| op).collect::<Vec<_>>()
         };
 
         let mut call_graph_depth_limit = match mutest_arg_matches.value_source("call-graph-depth-limit") {
@@ -396,6 +436,44 @@ pub fn main() {
             None | Some(clap::parser::ValueSource::DefaultValue) if let Some(c) = &package_config && let Some(v) = c.call_graph_trace_length_limit => Some(v),
             _ => mutest_arg_matches.get_one::<usize>("call-graph-trace-length-limit").copied(),
         };
+        let exclude_paths = {
+            // NOTE: Paths specified on the command line are relative to the package root,
+            //       while paths in the Cargo package manifest are already resolved.
+            let package_root_dir = env::var("CARGO_MANIFEST_DIR").map(PathBuf::from).unwrap_or_default();
+
+            let exclude_paths = match mutest_arg_matches.get_many::<PathBuf>("exclude-paths") {
+                Some(exclude_paths) => exclude_paths.map(|exclude_path| package_root_dir.join(exclude_path)).collect::<Vec<_>>(),
+                None => package_config.as_mut().and_then(|c| c.exclude_paths.take()).unwrap_or_default(),
+            };
+
+            exclude_paths.into_iter()
+                .map(|exclude_path| exclude_path.canonicalize().unwrap_or(exclude_path))
+                .collect::<Vec<_>>()
+        };
+
+        let ignored_mutations = mutest_arg_matches.get_one::<PathBuf>("ignore-file").map(|ignore_file_path| {
+            let package_root_dir = env::var("CARGO_MANIFEST_DIR").map(PathBuf::from).unwrap_or_default();
+            let ignore_file_path = package_root_dir.join(ignore_file_path);
+
+            let ignore_file_str = match fs::read_to_string(&ignore_file_path) {
+                Ok(ignore_file_str) => ignore_file_str,
+                Err(error) => {
+                    let mut diagnostic = early_dcx.early_struct_fatal(format!("cannot read ignore file `{}`", ignore_file_path.display()));
+                    diagnostic.note(error.to_string());
+                    diagnostic.emit();
+                }
+            };
+
+            match config::IgnoredMutations::parse(&ignore_file_str) {
+                Ok(ignored_mutations) => ignored_mutations,
+                Err(error) => {
+                    let mut diagnostic = early_dcx.early_struct_fatal(format!("invalid ignore file `{}`", ignore_file_path.display()));
+                    diagnostic.note(error);
+                    diagnostic.emit();
+                }
+            }
+        });
+
         let mutation_depth = match mutest_arg_matches.value_source("depth") {
             None | Some(clap::parser::ValueSource::DefaultValue) if let Some(c) = &package_config && let Some(v) = c.mutation_depth => v,
             _ => *mutest_arg_matches.get_one::<usize>("depth").unwrap(),
@@ -559,7 +637,10 @@ pub fn main() {
                 fs::create_dir_all(&out_dir).expect(&format!("cannot create JSON output directory for crate at `{}`", out_dir.display()));
             }
 
-            Some(config::WriteOptions { out_dir })
+            Some(config::WriteOptions {
+                out_dir,
+                compress: mutest_arg_matches.get_flag("Zwrite-json-compress"),
+            })
         };
 
         let verify_opts = {
@@ -601,9 +682,12 @@ pub fn main() {
                 print_opts,
                 unsafe_targeting,
                 operators: &mutation_operators,
+                exclude_paths,
                 call_graph_depth_limit,
                 call_graph_trace_length_limit,
                 mutation_depth,
+                max_mutations_per_target: mutest_arg_matches.get_one::<usize>("max-mutations-per-target").copied(),
+                ignored_mutations,
                 mutation_parallelism,
 
                 write_opts,
@@ -1,4 +1,5 @@
 use std::env;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use rustc_hash::{FxHashSet, FxHashMap};
@@ -7,7 +8,7 @@ use rustc_interface::interface::Result as CompilerResult;
 use rustc_middle::bug;
 use rustc_middle::ty::TyCtxt;
 use rustc_session::config::OptLevel;
-use rustc_span::ErrorGuaranteed;
+use rustc_span::{ErrorGuaranteed, FileName};
 use rustc_span::edition::Edition;
 use rustc_span::fatal_error::FatalError;
 use mutest_emit::analysis::call_graph::{EntryPointAssocs, EntryPoints, Targeting, TargetReachability};
@@ -22,8 +23,18 @@ use crate::passes::{Flow, base_compiler_config};
 use crate::passes::external_mutant::{ExternalTargets, StableTarget};
 use crate::passes::external_mutant::crate_const_storage;
 use crate::passes::external_mutant::specialized_crate::SpecializedMutantCrateCompilationRequest;
-use crate::print::{print_call_graph, print_mutations, print_mutation_graph, print_targets, print_tests};
-use crate::write::{write_call_graph, write_mutations, write_tests, write_timings};
+use crate::print::{print_call_graph, print_mutations, print_mutation_graph, print_spans, print_target_summary, print_targets, print_tests, print_untested_fns};
+use crate::write::{assign_mutation_content_hashes, write_call_graph, write_mutations, write_tests, write_timings};
+
+fn is_in_excluded_path(tcx: TyCtxt<'_>, def_id: hir::DefId, exclude_paths: &[PathBuf]) -> bool {
+    if exclude_paths.is_empty() { return false; }
+
+    let FileName::Real(file_name) = tcx.sess.source_map().span_to_filename(tcx.def_span(def_id)) else { return false; };
+    let Some(file_path) = file_name.local_path() else { return false; };
+    let file_path = file_path.canonicalize().unwrap_or_else(|_| file_path.to_owned());
+
+    exclude_paths.iter().any(|exclude_path| file_path.starts_with(exclude_path))
+}
 
 pub struct AnalysisPassResult {
     pub duration: Duration,
@@ -261,6 +272,9 @@ pub fn run(config: &mut Config) -> CompilerResult<Option<AnalysisPassResult>> {
 
             let all_mutable_fns_count = mutest_emit::analysis::call_graph::all_mutable_fns(tcx, external_meta_mutant_crate, &tests).count();
 
+            // Call spans are only collected if they are to be printed.
+            let mut call_spans = Vec::<(rustc_span::Span, String)>::new();
+
             let (entry_points, targets, json_definitions) = match &opts.crate_kind {
                 config::CrateKind::MutantForExternalTests(external_targets) => {
                     let entry_points = EntryPoints::External;
@@ -292,6 +306,12 @@ pub fn run(config: &mut Config) -> CompilerResult<Option<AnalysisPassResult>> {
                     let t_target_analysis_start = Instant::now();
 
                     let (call_graph, mut reachable_fns) = mutest_emit::analysis::call_graph::reachable_fns(tcx, &def_res, &generated_crate_ast, entry_points, targeting, call_graph_depth_limit, call_graph_trace_length_limit);
+                    if opts.print_opts.spans.is_some() {
+                        call_spans = call_graph.root_calls.values().flatten()
+                            .chain(call_graph.nested_calls.iter().flat_map(|calls| calls.values().flatten()))
+                            .map(|call| (call.span, tcx.def_path_str(call.callee.def_id)))
+                            .collect();
+                    }
                     let mut json_definitions = Default::default();
                     if let Some(write_opts) = &opts.write_opts {
                         let t_write_start = Instant::now();
@@ -329,6 +349,28 @@ pub fn run(config: &mut Config) -> CompilerResult<Option<AnalysisPassResult>> {
                         span_diagnostic_ord(target_a_span, target_b_span)
                     });
 
+                    if let Some(_) = opts.print_opts.untested_fns.take() {
+                        if opts.print_opts.print_headers { println!("\n@@@ untested functions @@@\n"); }
+                        let all_mutable_fns = mutest_emit::analysis::call_graph::all_mutable_fns(tcx, external_meta_mutant_crate, &tests);
+                        print_untested_fns(tcx, all_mutable_fns, &reachable_fns);
+                        if let config::Mode::Print = opts.mode && opts.print_opts.is_empty() {
+                            if let Some(write_opts) = &opts.write_opts {
+                                pass_result.duration = t_start.elapsed();
+                                pass_result.target_analysis_duration = t_target_analysis_start.elapsed();
+                                write_timings(write_opts, t_start.elapsed(), &pass_result, None, None);
+                            }
+                            if opts.report_timings {
+                                println!("\nfinished in {total:.2?} (targets {targets:.2?}; write {write:.2?})",
+                                    total = t_start.elapsed(),
+                                    targets = pass_result.test_discovery_duration + t_target_analysis_start.elapsed(),
+                                    write = pass_result.write_duration,
+                                );
+                            }
+                            return Flow::Break;
+                        }
+                        if opts.verbosity >= 1 { println!(); }
+                    }
+
                     if let Some(config::CallGraphOptions { format, entry_point_filters, non_local_call_view }) = opts.print_opts.call_graph.take() {
                         if opts.print_opts.print_headers { println!("\n@@@ call graph @@@\n"); }
                         print_call_graph(tcx, entry_points, &call_graph, &reachable_fns, format, &entry_point_filters, non_local_call_view);
@@ -355,6 +397,7 @@ pub fn run(config: &mut Config) -> CompilerResult<Option<AnalysisPassResult>> {
                             TargetReachability::DirectEntry => true,
                             TargetReachability::NestedCallee { distance } => distance < opts.mutation_depth,
                         })
+                        .filter(|f| !is_in_excluded_path(tcx, f.def_id(), &opts.exclude_paths))
                         .collect::<Vec<_>>();
 
                     pass_result.target_analysis_duration = t_target_analysis_start.elapsed();
@@ -447,7 +490,30 @@ pub fn run(config: &mut Config) -> CompilerResult<Option<AnalysisPassResult>> {
             }
 
             let t_mutation_generation_start = Instant::now();
-            let mutations = mutest_emit::codegen::mutation::apply_mutation_operators(tcx, &crate_res, &def_res, &body_res, &generated_crate_ast, &targets, &opts.operators, opts.unsafe_targeting, &sess_opts);
+            let mut mutations = mutest_emit::codegen::mutation::apply_mutation_operators(tcx, &crate_res, &def_res, &body_res, &generated_crate_ast, &targets, &opts.operators, opts.unsafe_targeting, &sess_opts);
+            assign_mutation_content_hashes(tcx, &mut mutations);
+            // NOTE: Mutations are ignored before any other mutations are dropped,
+            //       so that ignored mutation IDs refer to the mutations as originally generated.
+            let ignored_mutations = opts.ignored_mutations.as_ref().map(|ignored_mutations| {
+                let mut ignored_mutation_hashes = vec![];
+                let ignored_mutations_count = mutest_emit::codegen::mutation::retain_mutations(&mut mutations, |mutation| {
+                    let content_hash = mutation.content_hash.as_ref().expect("mutation content hash not assigned");
+                    let is_ignored = ignored_mutations.mutation_ids.contains(&mutation.id.index())
+                        || ignored_mutations.content_hashes.contains(content_hash);
+                    if is_ignored { ignored_mutation_hashes.push(mutest_json::mutations::MutationHash(content_hash.clone())); }
+                    !is_ignored
+                });
+                if opts.verbosity >= 1 && ignored_mutations_count >= 1 {
+                    println!("ignored {ignored_mutations_count} mutations listed in the ignore file");
+                }
+                ignored_mutation_hashes
+            });
+            if let Some(max_mutations_per_target) = opts.max_mutations_per_target {
+                let dropped_mutations_count = mutest_emit::codegen::mutation::limit_mutations_per_target(&mut mutations, max_mutations_per_target);
+                if opts.verbosity >= 1 && dropped_mutations_count >= 1 {
+                    println!("dropped {dropped_mutations_count} mutations from functions exceeding the limit of {max_mutations_per_target} mutations per function");
+                }
+            }
             if opts.verbosity >= 1 {
                 let mutated_fns = mutations.iter().map(|m| m.target.def_id()).collect::<FxHashSet<_>>();
                 let mutated_fns_count = mutated_fns.len();
@@ -483,6 +549,46 @@ pub fn run(config: &mut Config) -> CompilerResult<Option<AnalysisPassResult>> {
                 FatalError.raise();
             }
 
+            if let Some(file_path) = opts.print_opts.spans.take() {
+                if opts.print_opts.print_headers { println!("\n@@@ spans @@@\n"); }
+                print_spans(tcx, &file_path, &tests, &targets, &call_spans, &mutations);
+                if let config::Mode::Print = opts.mode && opts.print_opts.is_empty() {
+                    if let Some(write_opts) = &opts.write_opts {
+                        pass_result.duration = t_start.elapsed();
+                        write_timings(write_opts, t_start.elapsed(), &pass_result, None, None);
+                    }
+                    if opts.report_timings {
+                        println!("\nfinished in {total:.2?} (targets {targets:.2?}; mutations {mutations:.2?}; write {write:.2?})",
+                            total = t_start.elapsed(),
+                            targets = pass_result.test_discovery_duration + pass_result.target_analysis_duration,
+                            mutations = pass_result.mutation_generation_duration,
+                            write = pass_result.write_duration,
+                        );
+                    }
+                    return Flow::Break;
+                }
+            }
+
+            if let Some(_) = opts.print_opts.target_summary.take() {
+                if opts.print_opts.print_headers { println!("\n@@@ target summary @@@\n"); }
+                print_target_summary(tcx, &targets, &mutations);
+                if let config::Mode::Print = opts.mode && opts.print_opts.is_empty() {
+                    if let Some(write_opts) = &opts.write_opts {
+                        pass_result.duration = t_start.elapsed();
+                        write_timings(write_opts, t_start.elapsed(), &pass_result, None, None);
+                    }
+                    if opts.report_timings {
+                        println!("\nfinished in {total:.2?} (targets {targets:.2?}; mutations {mutations:.2?}; write {write:.2?})",
+                            total = t_start.elapsed(),
+                            targets = pass_result.test_discovery_duration + pass_result.target_analysis_duration,
+                            mutations = pass_result.mutation_generation_duration,
+                            write = pass_result.write_duration,
+                        );
+                    }
+                    return Flow::Break;
+                }
+            }
+
             let t_mutation_conflict_resolution_start = Instant::now();
             let mutation_conflict_graph = mutest_emit::codegen::mutation::generate_mutation_conflict_graph(&mutations, opts.unsafe_targeting);
             pass_result.mutation_conflict_resolution_duration = t_mutation_conflict_resolution_start.elapsed();
@@ -591,7 +697,7 @@ pub fn run(config: &mut Config) -> CompilerResult<Option<AnalysisPassResult>> {
 
             if let Some(write_opts) = &opts.write_opts {
                 let t_write_start = Instant::now();
-                write_mutations(write_opts, tcx, all_mutable_fns_count, &json_definitions, &targets, &mutations, opts.unsafe_targeting, &mutation_conflict_graph, mutation_parallelism, t_mutation_generation_start.elapsed());
+                write_mutations(write_opts, tcx, all_mutable_fns_count, &json_definitions, &targets, &mutations, opts.unsafe_targeting, &mutation_conflict_graph, mutation_parallelism, ignored_mutations, t_mutation_generation_start.elapsed());
                 pass_result.write_duration += t_write_start.elapsed();
             }
 
@@ -80,9 +80,12 @@ pub fn compile_specialized_mutant_crate(
             print_opts,
             unsafe_targeting: config.opts.unsafe_targeting,
             operators: config.opts.operators,
+            exclude_paths: config.opts.exclude_paths.clone(),
             call_graph_depth_limit: config.opts.call_graph_depth_limit,
             call_graph_trace_length_limit: config.opts.call_graph_trace_length_limit,
             mutation_depth: config.opts.mutation_depth,
+            max_mutations_per_target: config.opts.max_mutations_per_target,
+            ignored_mutations: config.opts.ignored_mutations.clone(),
             mutation_parallelism: config.opts.mutation_parallelism.clone(),
 
             write_opts: config.opts.write_opts.clone(),
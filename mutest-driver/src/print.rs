@@ -1,4 +1,5 @@
 use std::iter;
+use std::path::Path;
 
 use mutest_emit::analysis::call_graph::{CallGraph, Callee, EntryPoints, LocalEntryPoint, Target, TargetReachability, Unsafety};
 use mutest_emit::analysis::tests::Test;
@@ -7,6 +8,7 @@ use mutest_emit::codegen::mutation::{Mut, MutId, MutationBatch, MutationConflict
 use rustc_hash::{FxHashMap, FxHashSet};
 use rustc_middle::bug;
 use rustc_middle::ty::TyCtxt;
+use rustc_span::{FileName, Span};
 use rustc_span::def_id::DefId;
 use smallvec::{SmallVec, smallvec};
 
@@ -116,6 +118,132 @@ pub fn print_targets<'tcx, 'trg>(tcx: TyCtxt<'tcx>, crate_kind: &config::CrateKi
     );
 }
 
+pub fn print_target_summary<'tcx, 'trg, 'm>(tcx: TyCtxt<'tcx>, targets: &'trg [Target], mutations: &[Mut<'trg, 'm>]) {
+    let mut mutations_count_per_target = FxHashMap::<DefId, usize>::default();
+    for mutation in mutations {
+        *mutations_count_per_target.entry(mutation.target.def_id()).or_default() += 1;
+    }
+
+    // Targets are printed in source span order.
+    let mut targets_in_print_order = targets.iter()
+        .map(|target| (tcx.def_span(target.def_id()), target))
+        .collect::<Vec<_>>();
+    targets_in_print_order.sort_unstable_by(|(target_a_span, _), (target_b_span, _)| span_diagnostic_ord(*target_a_span, *target_b_span));
+
+    let rows = targets_in_print_order.into_iter()
+        .map(|(target_span, target)| {
+            let mutations_count = mutations_count_per_target.get(&target.def_id()).copied().unwrap_or(0);
+            let entry_points_count = target.reachable_from.iter().count();
+            (mutations_count, entry_points_count, tcx.def_path_str(target.def_id()), target_span)
+        })
+        .collect::<Vec<_>>();
+
+    const MUTATIONS_HEADER: &str = "mutations";
+    const ENTRY_POINTS_HEADER: &str = "entry points";
+    let mutations_width = rows.iter().map(|(mutations_count, ..)| mutations_count.to_string().len()).chain([MUTATIONS_HEADER.len()]).max().unwrap();
+    let entry_points_width = rows.iter().map(|(_, entry_points_count, ..)| entry_points_count.to_string().len()).chain([ENTRY_POINTS_HEADER.len()]).max().unwrap();
+
+    println!("{MUTATIONS_HEADER:>mutations_width$}  {ENTRY_POINTS_HEADER:>entry_points_width$}  target");
+
+    let mut unmutated_targets_count = 0;
+
+    for (mutations_count, entry_points_count, def_path, target_span) in &rows {
+        if *mutations_count == 0 { unmutated_targets_count += 1; }
+
+        println!("{mutations_count:>mutations_width$}  {entry_points_count:>entry_points_width$}  {def_path} at {target_span:#?}");
+    }
+    println!();
+
+    println!("targets: {total} total; {mutated} mutated; {unmutated} without mutations",
+        total = rows.len(),
+        mutated = rows.len() - unmutated_targets_count,
+        unmutated = unmutated_targets_count,
+    );
+}
+
+pub fn print_spans<'tcx, 'trg, 'm>(tcx: TyCtxt<'tcx>, file_path: &Path, tests: &[Test], targets: &'trg [Target], call_spans: &[(Span, String)], mutations: &[Mut<'trg, 'm>]) {
+    let source_map = tcx.sess.source_map();
+    let canonical_file_path = file_path.canonicalize().unwrap_or_else(|_| file_path.to_owned());
+
+    let mut spans = Vec::<(Span, &str, String)>::new();
+    spans.extend(tests.iter().map(|test| (test.item.span, "test", test.path_str())));
+    spans.extend(targets.iter().map(|target| (tcx.def_span(target.def_id()), "definition", tcx.def_path_str(target.def_id()))));
+    spans.extend(call_spans.iter().map(|(span, callee_path)| (*span, "call", format!("to {callee_path}"))));
+    spans.extend(mutations.iter().map(|m| (m.span, "mutation", format!("{} [{}]", m.id.index(), m.op_name()))));
+
+    spans.retain(|(span, _, _)| {
+        let FileName::Real(file_name) = source_map.span_to_filename(*span) else { return false; };
+        let Some(span_file_path) = file_name.local_path() else { return false; };
+        span_file_path.canonicalize().unwrap_or_else(|_| span_file_path.to_owned()) == canonical_file_path
+    });
+    // NOTE: Stable sort, so that spans of the same range are kept in the order of their kinds.
+    spans.sort_by(|(span_a, _, _), (span_b, _, _)| span_diagnostic_ord(*span_a, *span_b));
+
+    // Lines and columns are printed 1-based, with columns counted in characters, matching the spans of the JSON output.
+    // Byte offsets are 0-based, and relative to the start of the file as it is on disk (i.e. before normalization by rustc).
+    for (span, kind, desc) in &spans {
+        let lo = source_map.lookup_char_pos(span.lo());
+        let hi = source_map.lookup_char_pos(span.hi());
+
+        println!("{kind} {desc}: {lo_line}:{lo_col} (byte {lo_byte}) - {hi_line}:{hi_col} (byte {hi_byte})",
+            lo_line = lo.line,
+            lo_col = lo.col.0 + 1,
+            lo_byte = lo.file.original_relative_byte_pos(span.lo()).0,
+            hi_line = hi.line,
+            hi_col = hi.col.0 + 1,
+            hi_byte = hi.file.original_relative_byte_pos(span.hi()).0,
+        );
+    }
+    println!();
+
+    println!("spans: {total} total in `{file_path}`",
+        total = spans.len(),
+        file_path = file_path.display(),
+    );
+}
+
+pub fn print_untested_fns<'tcx>(tcx: TyCtxt<'tcx>, all_mutable_fns: impl Iterator<Item = DefId>, reachable_fns: &[Target]) {
+    let source_map = tcx.sess.source_map();
+
+    let reachable_fn_def_ids = reachable_fns.iter().map(|target| target.def_id()).collect::<FxHashSet<_>>();
+
+    let mut all_mutable_fns_count = 0;
+
+    // Functions are printed grouped by source file, in lexical order of the file paths, and in source span order within.
+    let mut untested_fns_in_print_order = all_mutable_fns
+        .inspect(|_| all_mutable_fns_count += 1)
+        .filter(|def_id| !reachable_fn_def_ids.contains(def_id))
+        .map(|def_id| {
+            let span = tcx.def_span(def_id);
+            (source_map.span_to_filename(span).prefer_local().to_string(), span, def_id)
+        })
+        .collect::<Vec<_>>();
+    untested_fns_in_print_order.sort_unstable_by(|(file_a, span_a, _), (file_b, span_b, _)| {
+        Ord::cmp(file_a, file_b).then(span_diagnostic_ord(*span_a, *span_b))
+    });
+
+    let untested_fns_count = untested_fns_in_print_order.len();
+
+    for (file_name, fns) in untested_fns_in_print_order.chunk_by(|(file_a, _, _), (file_b, _, _)| file_a == file_b).map(|fns| (&fns[0].0, fns)) {
+        println!("{file_name}:");
+        for (_, span, def_id) in fns {
+            let lo = source_map.lookup_char_pos(span.lo());
+
+            println!("  {def_path} at {line}:{col}",
+                def_path = tcx.def_path_str(*def_id),
+                line = lo.line,
+                col = lo.col.0 + 1,
+            );
+        }
+        println!();
+    }
+
+    println!("untested functions: {untested} out of {total} functions",
+        untested = untested_fns_count,
+        total = all_mutable_fns_count,
+    );
+}
+
 pub fn print_call_graph<'tcx, 'ent, 'trg>(tcx: TyCtxt<'tcx>, entry_points: EntryPoints<'ent>, call_graph: &CallGraph<'tcx>, targets: &[Target], format: config::GraphFormat, entry_point_filters: &[String], non_local_call_view: config::CallGraphNonLocalCallView) {
     if let EntryPoints::External = entry_points {
         bug!("cannot print call graph for external entry points");
@@ -4,6 +4,8 @@ use std::fs;
 use std::io::BufWriter;
 use std::time::Duration;
 
+use flate2::Compression;
+use flate2::write::GzEncoder;
 use mutest_emit::analysis::call_graph::{CallGraph, Callee, EntryPoints, Target, TargetKind, TargetReachability, Unsafety};
 use mutest_emit::analysis::hir;
 use mutest_emit::analysis::tests::Test;
@@ -12,6 +14,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use rustc_middle::bug;
 use rustc_middle::ty::TyCtxt;
 use rustc_span::def_id::{DefPathHash, LocalDefId};
+use smallvec::SmallVec;
 
 use crate::config::WriteOptions;
 use crate::passes::analysis::AnalysisPassResult;
@@ -25,6 +28,19 @@ fn write_metadata<T: serde::Serialize>(write_opts: &WriteOptions, file_name: &st
     serde_json::to_writer(&mut buffered_file, &data).expect("cannot write metadata file");
 }
 
+/// Writes a potentially large metadata file, compressing it into a `.json.gz` file if requested.
+fn write_large_metadata<T: serde::Serialize>(write_opts: &WriteOptions, file_name: &str, data: &T) {
+    if !write_opts.compress {
+        return write_metadata(write_opts, file_name, data);
+    }
+
+    let file = fs::File::create(write_opts.out_dir.join(format!("{file_name}.gz"))).expect("cannot create metadata file");
+    let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+
+    serde_json::to_writer(&mut encoder, &data).expect("cannot write metadata file");
+    encoder.finish().expect("cannot write metadata file");
+}
+
 pub fn write_tests<'tcx>(write_opts: &WriteOptions, tcx: TyCtxt<'tcx>, tests: &[Test], duration: Duration) {
     write_metadata(write_opts, "tests.json", &mutest_json::tests::TestsInfo {
         format_version: mutest_json::FORMAT_VERSION,
@@ -159,7 +175,7 @@ pub fn write_call_graph<'tcx, 'ent>(
         }
     }
 
-    write_metadata(write_opts, "call_graph.json", &mutest_json::call_graph::CallGraphInfo {
+    write_large_metadata(write_opts, "call_graph.json", &mutest_json::call_graph::CallGraphInfo {
         format_version: mutest_json::FORMAT_VERSION,
         stats: mutest_json::call_graph::CallGraphStats {
             all_mutable_fns_count,
@@ -185,6 +201,66 @@ pub fn write_call_graph<'tcx, 'ent>(
     unique_definitions
 }
 
+fn json_substs(tcx: TyCtxt<'_>, mutation: &Mut) -> SmallVec<[mutest_json::mutations::Substitution; 1]> {
+    mutation.substs.iter()
+        .map(|subst| {
+            mutest_json::mutations::Substitution {
+                location: match &subst.location {
+                    SubstLoc::InsertBefore(_, span) => {
+                        let subst_span = mutest_json::Span::from_rustc_span(tcx.sess, *span).expect("invalid span");
+                        mutest_json::mutations::SubstitutionLocation::InsertBefore(subst_span)
+                    }
+                    SubstLoc::InsertAfter(_, span) => {
+                        let subst_span = mutest_json::Span::from_rustc_span(tcx.sess, *span).expect("invalid span");
+                        mutest_json::mutations::SubstitutionLocation::InsertAfter(subst_span)
+                    }
+                    SubstLoc::Replace(_, span) => {
+                        let subst_span = mutest_json::Span::from_rustc_span(tcx.sess, *span).expect("invalid span");
+                        mutest_json::mutations::SubstitutionLocation::Replace(subst_span)
+                    }
+                },
+                substitute: mutest_json::mutations::Substitute {
+                    kind: match &subst.substitute {
+                        Subst::AstExpr(..) => mutest_json::mutations::SubstituteKind::Expr,
+                        Subst::AstStmt(..) => mutest_json::mutations::SubstituteKind::Stmt,
+                        Subst::AstLocal(..) => mutest_json::mutations::SubstituteKind::Local,
+                    },
+                    replacement: subst.substitute.to_source_string(),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Assigns content hashes to the mutations, which identify the mutations across runs.
+///
+/// Identical mutations within the same target are told apart by their occurrence index (see [`mutest_json::mutations::MutationHash`]),
+/// so the hashes must be assigned to all generated mutations at once, in generation order, before any mutations are dropped.
+/// These are the same content hashes that are written into the mutations JSON file.
+pub fn assign_mutation_content_hashes(tcx: TyCtxt<'_>, mutations: &mut [Mut]) {
+    let mut occurrences = FxHashMap::<mutest_json::mutations::MutationHash, usize>::default();
+
+    for mutation in mutations {
+        let origin_span = mutest_json::Span::from_rustc_span(tcx.sess, mutation.span).expect("invalid span");
+        let substs = json_substs(tcx, mutation);
+        let target_def_path = tcx.def_path_str(mutation.target.def_id());
+
+        let first_occurrence_hash = mutest_json::mutations::MutationHash::compute(&origin_span.path, &target_def_path, mutation.op_name(), &substs, 0);
+        let occurrence = {
+            let next_occurrence = occurrences.entry(first_occurrence_hash.clone()).or_default();
+            let occurrence = *next_occurrence;
+            *next_occurrence += 1;
+            occurrence
+        };
+
+        let content_hash = match occurrence {
+            0 => first_occurrence_hash,
+            _ => mutest_json::mutations::MutationHash::compute(&origin_span.path, &target_def_path, mutation.op_name(), &substs, occurrence),
+        };
+        mutation.content_hash = Some(content_hash.0);
+    }
+}
+
 pub fn write_mutations<'tcx, 'trg>(
     write_opts: &WriteOptions,
     tcx: TyCtxt<'tcx>,
@@ -195,6 +271,7 @@ pub fn write_mutations<'tcx, 'trg>(
     unsafe_targeting: UnsafeTargeting,
     mutation_conflict_graph: &MutationConflictGraph,
     mutation_parallelism: Option<MutationParallelism>,
+    ignored_mutations: Option<Vec<mutest_json::mutations::MutationHash>>,
     duration: Duration,
 ) {
     let total_mutations_count = mutations.len();
@@ -243,34 +320,7 @@ pub fn write_mutations<'tcx, 'trg>(
 
         let origin_span = mutest_json::Span::from_rustc_span(tcx.sess, mutation.span).expect("invalid span");
 
-        let substs = mutation.substs.iter()
-            .map(|subst| {
-                mutest_json::mutations::Substitution {
-                    location: match &subst.location {
-                        SubstLoc::InsertBefore(_, span) => {
-                            let subst_span = mutest_json::Span::from_rustc_span(tcx.sess, *span).expect("invalid span");
-                            mutest_json::mutations::SubstitutionLocation::InsertBefore(subst_span)
-                        }
-                        SubstLoc::InsertAfter(_, span) => {
-                            let subst_span = mutest_json::Span::from_rustc_span(tcx.sess, *span).expect("invalid span");
-                            mutest_json::mutations::SubstitutionLocation::InsertAfter(subst_span)
-                        }
-                        SubstLoc::Replace(_, span) => {
-                            let subst_span = mutest_json::Span::from_rustc_span(tcx.sess, *span).expect("invalid span");
-                            mutest_json::mutations::SubstitutionLocation::Replace(subst_span)
-                        }
-                    },
-                    substitute: mutest_json::mutations::Substitute {
-                        kind: match &subst.substitute {
-                            Subst::AstExpr(..) => mutest_json::mutations::SubstituteKind::Expr,
-                            Subst::AstStmt(..) => mutest_json::mutations::SubstituteKind::Stmt,
-                            Subst::AstLocal(..) => mutest_json::mutations::SubstituteKind::Local,
-                        },
-                        replacement: subst.substitute.to_source_string(),
-                    },
-                }
-            })
-            .collect();
+        let substs = json_substs(tcx, mutation);
 
         json_mutations.push(mutest_json::mutations::Mutation {
             mutation_id,
@@ -284,6 +334,7 @@ pub fn write_mutations<'tcx, 'trg>(
                 (true, _) => mutest_json::mutations::MutationSafety::Unsafe,
                 (false, _) => mutest_json::mutations::MutationSafety::Safe,
             },
+            content_hash: mutation.content_hash.clone().map(mutest_json::mutations::MutationHash),
         });
     }
 
@@ -366,7 +417,7 @@ pub fn write_mutations<'tcx, 'trg>(
         }
     }
 
-    write_metadata(write_opts, "mutations.json", &mutest_json::mutations::MutationsInfo {
+    write_large_metadata(write_opts, "mutations.json", &mutest_json::mutations::MutationsInfo {
         format_version: mutest_json::FORMAT_VERSION,
         stats: mutest_json::mutations::MutationStats {
             all_mutable_fns_count,
@@ -385,6 +436,7 @@ pub fn write_mutations<'tcx, 'trg>(
         mutations: json_mutations,
         mutation_batches,
         targets: json_targets,
+        ignored_mutations,
         duration,
     });
 }
@@ -1643,11 +1643,16 @@ pub mod visit {
     }
 
     pub trait VisitWithHirNode {
-        fn visit<'ast, 'hir, T: AstHirVisitor<'ast, 'hir>>(&'ast self, visitor: &mut T, node_hir: hir::Node<'hir>) -> bool;
+        /// Additional AST context of the item, e.g. the `AssocCtxt` of associated items.
+        type Ctxt: Copy;
+
+        fn visit<'ast, 'hir, T: AstHirVisitor<'ast, 'hir>>(&'ast self, ctxt: Self::Ctxt, visitor: &mut T, node_hir: hir::Node<'hir>) -> bool;
     }
 
     impl VisitWithHirNode for ast::Item {
-        fn visit<'ast, 'hir, T: AstHirVisitor<'ast, 'hir>>(&'ast self, visitor: &mut T, node_hir: hir::Node<'hir>) -> bool {
+        type Ctxt = ();
+
+        fn visit<'ast, 'hir, T: AstHirVisitor<'ast, 'hir>>(&'ast self, (): Self::Ctxt, visitor: &mut T, node_hir: hir::Node<'hir>) -> bool {
             let tcx = visitor.tcx();
 
             match &self.kind {
@@ -1701,12 +1706,14 @@ pub mod visit {
     }
 
     impl VisitWithHirNode for ast::AssocItem {
-        fn visit<'ast, 'hir, T: AstHirVisitor<'ast, 'hir>>(&'ast self, visitor: &mut T, node_hir: hir::Node<'hir>) -> bool {
+        type Ctxt = ast::visit::AssocCtxt;
+
+        fn visit<'ast, 'hir, T: AstHirVisitor<'ast, 'hir>>(&'ast self, ctxt: Self::Ctxt, visitor: &mut T, node_hir: hir::Node<'hir>) -> bool {
             let tcx = visitor.tcx();
 
             match &self.kind {
                 ast::AssocItemKind::Fn(_) => {
-                    let Some(fn_ast) = ast::FnItem::from_assoc_item(self) else { unreachable!() };
+                    let Some(fn_ast) = ast::FnItem::from_assoc_item(self, ctxt) else { unreachable!() };
                     let Some(fn_hir) = hir::FnItem::from_node(tcx, node_hir) else { panic!("mismatched HIR node") };
                     AstHirVisitor::visit_fn_item(visitor, &fn_ast, &fn_hir);
                 }
@@ -2032,13 +2039,13 @@ impl<'ast, 'hir, 'op> visit::AstHirVisitor<'ast, 'hir> for BodyResolutionsCollec
     }
 }
 
-pub fn resolve_body<'tcx, K>(tcx: TyCtxt<'tcx>, def_res: &DefResolutions, item_ast: &ast::Item<K>, node_hir: hir::Node<'tcx>) -> Option<BodyResolutions<'tcx>>
+pub fn resolve_body<'tcx, K>(tcx: TyCtxt<'tcx>, def_res: &DefResolutions, item_ast: &ast::Item<K>, item_ctxt: <ast::Item<K> as visit::VisitWithHirNode>::Ctxt, node_hir: hir::Node<'tcx>) -> Option<BodyResolutions<'tcx>>
 where
     ast::Item<K>: visit::VisitWithHirNode,
 {
     let mut collector = BodyResolutionsCollector::new(tcx, def_res);
 
-    let visited = visit::VisitWithHirNode::visit(item_ast, &mut collector, node_hir);
+    let visited = visit::VisitWithHirNode::visit(item_ast, item_ctxt, &mut collector, node_hir);
     if !visited { return None; }
 
     Some(collector.finalize())
@@ -2058,7 +2065,7 @@ impl<'ast, 'hir, T: visit::AstHirVisitor<'ast, 'hir>> ast::visit::Visitor<'ast>
     fn visit_item(&mut self, item: &'ast ast::Item) {
         let Some(&def_id) = self.visitor.def_res().node_id_to_def_id.get(&item.id) else { return; };
         let node_hir = self.visitor.tcx().hir_node_by_def_id(def_id);
-        visit::VisitWithHirNode::visit(item, &mut self.visitor, node_hir);
+        visit::VisitWithHirNode::visit(item, (), &mut self.visitor, node_hir);
 
         ast::visit::walk_item(self, item);
     }
@@ -2066,7 +2073,7 @@ impl<'ast, 'hir, T: visit::AstHirVisitor<'ast, 'hir>> ast::visit::Visitor<'ast>
     fn visit_assoc_item(&mut self, assoc_item: &'ast ast::AssocItem, assoc_ctxt: ast::visit::AssocCtxt) {
         let Some(&def_id) = self.visitor.def_res().node_id_to_def_id.get(&assoc_item.id) else { return; };
         let node_hir = self.visitor.tcx().hir_node_by_def_id(def_id);
-        visit::VisitWithHirNode::visit(assoc_item, &mut self.visitor, node_hir);
+        visit::VisitWithHirNode::visit(assoc_item, assoc_ctxt, &mut self.visitor, node_hir);
 
         ast::visit::walk_assoc_item(self, assoc_item, assoc_ctxt);
     }
@@ -2557,19 +2564,19 @@ where
             }
             hir::ItemKind::Use(_, _) => None,
             hir::ItemKind::Static(_, _, _, _) => {
-                matching_item!(ast::DefItemKind::Static(_) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::Static(_) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::Const(_, _, _, _) => {
-                matching_item!(ast::DefItemKind::Const(_) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::Const(_) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::Fn { .. } => {
-                matching_item!(ast::DefItemKind::Fn(_) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::Fn(_) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::Macro(_, def_hir, _) => {
                 matching_item!(ast::DefItemKind::MacroDef(_, def_ast) if std::ptr::eq(*def_hir, *def_ast))
             }
             hir::ItemKind::Mod(_, _) => {
-                matching_item!(ast::DefItemKind::Mod(_, _, _) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::Mod(_, _, _) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::ForeignMod { .. } => {
                 let Some(disambiguator) = disambiguator else { return None; };
@@ -2588,22 +2595,22 @@ where
                 items_ast.into_iter().filter(|&item_ast| matches!(&item_ast.kind(), ast::DefItemKind::GlobalAsm(_))).nth(index)
             }
             hir::ItemKind::TyAlias(_, _, _) => {
-                matching_item!(ast::DefItemKind::TyAlias(_) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::TyAlias(_) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::Enum(_, _, _) => {
-                matching_item!(ast::DefItemKind::Enum(_, _, _) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::Enum(_, _, _) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::Struct(_, _, _) => {
-                matching_item!(ast::DefItemKind::Struct(_, _, _) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::Struct(_, _, _) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::Union(_ ,_, _) => {
-                matching_item!(ast::DefItemKind::Union(_, _, _) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::Union(_, _, _) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::Trait(_, _, _, _, _, _, _) => {
-                matching_item!(ast::DefItemKind::Trait(_) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::Trait(_) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::TraitAlias(_, _, _, _) => {
-                matching_item!(ast::DefItemKind::TraitAlias(_) => |item_ast| Some(item_ast.ident()) == item_hir.kind.ident())
+                matching_item!(ast::DefItemKind::TraitAlias(_) => |item_ast| item_ast.ident() == item_hir.kind.ident())
             }
             hir::ItemKind::Impl(_) => {
                 let Some(disambiguator) = disambiguator else { return None; };
@@ -2616,35 +2623,35 @@ where
         }
         hir::DefItem::ForeignItem(item_hir) => match &item_hir.kind {
             hir::ForeignItemKind::Fn(_, _, _) => {
-                matching_item!(ast::DefItemKind::Fn(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::Fn(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
             hir::ForeignItemKind::Static(_, _, _) => {
-                matching_item!(ast::DefItemKind::Static(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::Static(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
             hir::ForeignItemKind::Type => {
-                matching_item!(ast::DefItemKind::TyAlias(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::TyAlias(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
         }
         hir::DefItem::TraitItem(item_hir) => match &item_hir.kind {
             hir::TraitItemKind::Const(_, _) => {
-                matching_item!(ast::DefItemKind::Const(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::Const(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
             hir::TraitItemKind::Fn(_, _) => {
-                matching_item!(ast::DefItemKind::Fn(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::Fn(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
             hir::TraitItemKind::Type(_, _) => {
-                matching_item!(ast::DefItemKind::TyAlias(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::TyAlias(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
         }
         hir::DefItem::ImplItem(item_hir) => match &item_hir.kind {
             hir::ImplItemKind::Const(_, _) => {
-                matching_item!(ast::DefItemKind::Const(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::Const(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
             hir::ImplItemKind::Fn(_, _) => {
-                matching_item!(ast::DefItemKind::Fn(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::Fn(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
             hir::ImplItemKind::Type(_) => {
-                matching_item!(ast::DefItemKind::TyAlias(_) => |item_ast| item_ast.ident() == item_hir.ident)
+                matching_item!(ast::DefItemKind::TyAlias(_) => |item_ast| item_ast.ident() == Some(item_hir.ident))
             }
         }
     }
@@ -25,10 +25,10 @@ impl<'ast> FnItem<'ast> {
         Some(Self { id, span, ctx, vis, fn_data: fn_item })
     }
 
-    pub fn from_assoc_item(item: &'ast ast::AssocItem) -> Option<Self> {
+    pub fn from_assoc_item(item: &'ast ast::AssocItem, assoc_ctx: visit::AssocCtxt) -> Option<Self> {
         let &ast::Item { id, span, ref vis, ref kind, .. } = item;
         let ast::AssocItemKind::Fn(fn_item) = kind else { return None; };
-        let ctx = visit::FnCtxt::Free; // FIXME
+        let ctx = visit::FnCtxt::Assoc(assoc_ctx);
         Some(Self { id, span, ctx, vis, fn_data: fn_item })
     }
 }
@@ -117,14 +117,12 @@ impl<'ast> DefItem<'ast> {
         }
     }
 
-    pub fn ident(&self) -> Ident {
+    /// Returns the ident of the item, or `None` for items without one (e.g. `impl` blocks, `use` declarations).
+    pub fn ident(&self) -> Option<Ident> {
         match self {
-            Self::Item(item) if let Some(ident) = item.kind.ident() => ident,
-            Self::Item(item) => panic!("{} does not have ident", item.kind.descr()),
-            Self::ForeignItem(item) if let Some(ident) = item.kind.ident() => ident,
-            Self::ForeignItem(item) => panic!("{} does not have ident", item.kind.descr()),
-            Self::AssocItem(item, _) if let Some(ident) = item.kind.ident() => ident,
-            Self::AssocItem(item, _) => panic!("{} does not have ident", item.kind.descr()),
+            Self::Item(item) => item.kind.ident(),
+            Self::ForeignItem(item) => item.kind.ident(),
+            Self::AssocItem(item, _) => item.kind.ident(),
         }
     }
 
@@ -392,6 +390,10 @@ pub mod mk {
         self::expr_unary(sp, ast::UnOp::Deref, expr)
     }
 
+    pub fn expr_try(sp: Span, expr: Box<ast::Expr>) -> Box<ast::Expr> {
+        self::expr(sp, ast::ExprKind::Try(expr))
+    }
+
     pub fn expr_addr_of(sp: Span, borrow: ast::BorrowKind, mutbl: ast::Mutability, expr: Box<ast::Expr>) -> Box<ast::Expr> {
         self::expr(sp, ast::ExprKind::AddrOf(borrow, mutbl, expr))
     }
@@ -1117,7 +1119,7 @@ pub mod inspect {
     use std::iter;
 
     use rustc_ast as ast;
-    use rustc_span::Symbol;
+    use rustc_span::{Symbol, sym};
 
     pub fn match_attr_name(attr: &ast::Attribute, tool: Option<Symbol>, name: Symbol) -> bool {
         let ast::AttrKind::Normal(attr_item) = &attr.kind else { return false; };
@@ -1171,6 +1173,97 @@ pub mod inspect {
 
         false
     }
+
+    /// Matches `#[cfg(...)]` attributes, including the `#[<cfg_trace>(...)]` attributes left behind by
+    /// the compiler in place of `#[cfg(...)]` attributes that evaluated to true during expansion.
+    pub fn is_cfg_attr(attr: &ast::Attribute) -> bool {
+        match_attr_name(attr, None, sym::cfg) || match_attr_name(attr, None, sym::cfg_trace)
+    }
+
+    pub fn has_cfg_attr(attrs: &[ast::Attribute]) -> bool {
+        attrs.iter().any(is_cfg_attr)
+    }
+
+    pub fn is_cfg_test_attr(attr: &ast::Attribute) -> bool {
+        is_list_attr_with_ident(attr, None, sym::cfg, sym::test) || is_list_attr_with_ident(attr, None, sym::cfg_trace, sym::test)
+    }
+
+    /// Matches items marked with the built-in `#[test]` attribute (or its expanded `#[rustc_test_marker]` form),
+    /// and items, such as modules, which are only compiled in test mode using `#[cfg(test)]`.
+    pub fn is_test_item(item: &ast::Item) -> bool {
+        item.attrs.iter().any(|attr| {
+            is_word_attr(attr, None, sym::test)
+                || match_attr_name(attr, None, sym::rustc_test_marker)
+                || is_cfg_test_attr(attr)
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use rustc_ast as ast;
+        use rustc_span::{DUMMY_SP, Symbol, sym};
+        use rustc_span::symbol::Ident;
+        use thin_vec::thin_vec;
+
+        use crate::codegen::ast::mk;
+
+        use super::{has_cfg_attr, is_cfg_attr, is_cfg_test_attr, is_test_item};
+
+        fn list_attr(g: &ast::attr::AttrIdGenerator, name: Symbol, ident: Symbol) -> ast::Attribute {
+            let args = mk::attr_args_delimited(DUMMY_SP, ast::token::Delimiter::Parenthesis, mk::token_stream(vec![
+                mk::tt_token_alone(DUMMY_SP, ast::token::TokenKind::Ident(ident, ast::token::IdentIsRaw::No)),
+            ]));
+            mk::attr_outer(g, DUMMY_SP, ast::Safety::Default, Ident::new(name, DUMMY_SP), args)
+        }
+
+        fn word_attr(g: &ast::attr::AttrIdGenerator, name: Symbol) -> ast::Attribute {
+            mk::attr_outer(g, DUMMY_SP, ast::Safety::Default, Ident::new(name, DUMMY_SP), ast::AttrArgs::Empty)
+        }
+
+        fn item_with_attrs(attrs: Vec<ast::Attribute>) -> Box<ast::Item> {
+            mk::item(DUMMY_SP, attrs.into_iter().collect(), mk::vis_default(DUMMY_SP), ast::ItemKind::Mod(
+                ast::Safety::Default,
+                Ident::new(sym::test, DUMMY_SP),
+                ast::ModKind::Loaded(thin_vec![], ast::Inline::Yes, Default::default()),
+            ))
+        }
+
+        #[test]
+        fn test_cfg_attrs() {
+            rustc_span::create_default_session_globals_then(|| {
+                let g = ast::attr::AttrIdGenerator::new();
+
+                assert!(is_cfg_attr(&list_attr(&g, sym::cfg, sym::unix)));
+                assert!(is_cfg_attr(&list_attr(&g, sym::cfg_trace, sym::unix)));
+                assert!(!is_cfg_attr(&list_attr(&g, sym::allow, sym::dead_code)));
+                assert!(!is_cfg_attr(&word_attr(&g, sym::test)));
+
+                assert!(is_cfg_test_attr(&list_attr(&g, sym::cfg, sym::test)));
+                assert!(is_cfg_test_attr(&list_attr(&g, sym::cfg_trace, sym::test)));
+                assert!(!is_cfg_test_attr(&list_attr(&g, sym::cfg, sym::unix)));
+
+                assert!(has_cfg_attr(&[word_attr(&g, sym::inline), list_attr(&g, sym::cfg, sym::unix)]));
+                assert!(!has_cfg_attr(&[word_attr(&g, sym::inline)]));
+                assert!(!has_cfg_attr(&[]));
+            });
+        }
+
+        #[test]
+        fn test_test_items() {
+            rustc_span::create_default_session_globals_then(|| {
+                let g = ast::attr::AttrIdGenerator::new();
+
+                assert!(is_test_item(&item_with_attrs(vec![word_attr(&g, sym::test)])));
+                assert!(is_test_item(&item_with_attrs(vec![word_attr(&g, sym::rustc_test_marker)])));
+                assert!(is_test_item(&item_with_attrs(vec![list_attr(&g, sym::cfg, sym::test)])));
+                assert!(is_test_item(&item_with_attrs(vec![list_attr(&g, sym::cfg_trace, sym::test)])));
+
+                assert!(!is_test_item(&item_with_attrs(vec![])));
+                assert!(!is_test_item(&item_with_attrs(vec![list_attr(&g, sym::cfg, sym::unix)])));
+                assert!(!is_test_item(&item_with_attrs(vec![list_attr(&g, sym::allow, sym::test)])));
+            });
+        }
+    }
 }
 
 pub mod mut_visit {
@@ -1209,3 +1302,52 @@ pub mod mut_visit {
         vis.visit_span(&mut assoc_item_constraint.span);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches::assert_matches;
+
+    use rustc_ast as ast;
+    use rustc_ast::visit::{AssocCtxt, FnCtxt};
+    use rustc_span::{DUMMY_SP, Symbol};
+    use rustc_span::symbol::Ident;
+    use thin_vec::ThinVec;
+
+    use super::{FnItem, mk};
+
+    fn fn_item(name: &str) -> Box<ast::Item> {
+        let ident = Ident::new(Symbol::intern(name), DUMMY_SP);
+        mk::item_fn(DUMMY_SP, mk::vis_default(DUMMY_SP), ident, None, None, ThinVec::new(), None, Some(mk::block(DUMMY_SP, ThinVec::new())))
+    }
+
+    fn assoc_fn_item(name: &str) -> ast::AssocItem {
+        let ast::Item { attrs, id, span, vis, kind, tokens } = *fn_item(name);
+        let ast::ItemKind::Fn(fn_data) = kind else { unreachable!() };
+        ast::AssocItem { attrs, id, span, vis, kind: ast::AssocItemKind::Fn(fn_data), tokens }
+    }
+
+    #[test]
+    fn test_fn_item_from_item_is_free() {
+        rustc_span::create_default_session_globals_then(|| {
+            let item = fn_item("f");
+            let Some(fn_item) = FnItem::from_item(&item) else { panic!("expected fn item") };
+            assert_matches!(fn_item.ctx, FnCtxt::Free);
+        });
+    }
+
+    #[test]
+    fn test_fn_item_from_assoc_item_carries_assoc_ctxt() {
+        rustc_span::create_default_session_globals_then(|| {
+            let item = assoc_fn_item("method");
+
+            let Some(fn_item) = FnItem::from_assoc_item(&item, AssocCtxt::Impl { of_trait: false }) else { panic!("expected fn item") };
+            assert_matches!(fn_item.ctx, FnCtxt::Assoc(AssocCtxt::Impl { of_trait: false }));
+
+            let Some(fn_item) = FnItem::from_assoc_item(&item, AssocCtxt::Impl { of_trait: true }) else { panic!("expected fn item") };
+            assert_matches!(fn_item.ctx, FnCtxt::Assoc(AssocCtxt::Impl { of_trait: true }));
+
+            let Some(fn_item) = FnItem::from_assoc_item(&item, AssocCtxt::Trait) else { panic!("expected fn item") };
+            assert_matches!(fn_item.ctx, FnCtxt::Assoc(AssocCtxt::Trait));
+        });
+    }
+}
@@ -78,6 +78,10 @@ pub fn bake_mutation<'tcx, 'ent>(sp: Span, tcx: TyCtxt<'tcx>, entry_points: Entr
         ast::mk::expr_struct_field(sp, Ident::new(sym::display_location, sp), {
             ast::mk::expr_str(sp, &diagnostic::escape_literal(&mutation.display_location(tcx.sess)))
         }),
+        ast::mk::expr_struct_field(sp, Ident::new(sym::content_hash, sp), {
+            let Some(content_hash) = &mutation.content_hash else { bug!("mutation content hash not assigned"); };
+            ast::mk::expr_str(sp, content_hash)
+        }),
 
         ast::mk::expr_struct_field(sp, Ident::new(sym::reachable_from, sp), {
             // FIXME: Decide whether we want to support this, or remove it instead.
@@ -225,6 +225,9 @@ pub struct Mut<'trg, 'm> {
     pub is_in_unsafe_block: bool,
     pub mutation: BoxedMutation<'m>,
     pub substs: SmallVec<[SubstDef; 1]>,
+    /// Content hash of the mutation, identifying the mutation across compilations, unlike its ID.
+    /// This is assigned by the driver once all mutations have been generated.
+    pub content_hash: Option<String>,
 }
 
 impl<'trg, 'm> Mut<'trg, 'm> {
@@ -345,6 +348,7 @@ macro register_mutations($self:ident, $($mcx:tt)+) {
                     is_in_unsafe_block: $self.is_in_unsafe_block,
                     mutation,
                     substs,
+                    content_hash: None,
                 });
 
                 $self.next_mut_index += 1;
@@ -641,6 +645,51 @@ pub fn apply_mutation_operators<'ast, 'tcx, 'trg, 'm>(
     collector.mutations
 }
 
+/// Removes the mutations for which the predicate returns `false`.
+/// The remaining mutations are renumbered, so that mutation IDs remain contiguous.
+///
+/// Returns the number of mutations that were removed.
+pub fn retain_mutations<'trg, 'm>(mutations: &mut Vec<Mut<'trg, 'm>>, f: impl FnMut(&Mut<'trg, 'm>) -> bool) -> usize {
+    let original_mutations_count = mutations.len();
+
+    mutations.retain(f);
+
+    for (idx, mutation) in mutations.iter_mut().enumerate() {
+        mutation.id = MutId(idx as u32 + 1);
+    }
+
+    original_mutations_count - mutations.len()
+}
+
+/// Limits the number of mutations in each target to at most `max_mutations_per_target`,
+/// by deterministically sampling mutations evenly spread across the mutations of the target.
+/// The remaining mutations are renumbered (see [`retain_mutations`]).
+///
+/// Returns the number of mutations that were dropped.
+pub fn limit_mutations_per_target<'trg, 'm>(mutations: &mut Vec<Mut<'trg, 'm>>, max_mutations_per_target: usize) -> usize {
+    let mut mutations_count_per_target = FxHashMap::<hir::DefId, usize>::default();
+    for mutation in mutations.iter() {
+        *mutations_count_per_target.entry(mutation.target.def_id()).or_default() += 1;
+    }
+
+    let mut next_index_per_target = FxHashMap::<hir::DefId, usize>::default();
+    retain_mutations(mutations, |mutation| {
+        let target_def_id = mutation.target.def_id();
+        let mutations_count = mutations_count_per_target[&target_def_id];
+
+        let next_index = next_index_per_target.entry(target_def_id).or_default();
+        let index = *next_index;
+        *next_index += 1;
+
+        if mutations_count <= max_mutations_per_target { return true; }
+
+        // Keep the mutations at indices `floor(k * n / max)` for `k` in `0..max`, where `n` is the number of mutations
+        // in the target. Since `n > max`, each kept index corresponds to exactly one `k`, which is `ceil(index * max / n)`.
+        let k = (index * max_mutations_per_target).div_ceil(mutations_count);
+        k < max_mutations_per_target && k * mutations_count / max_mutations_per_target == index
+    })
+}
+
 pub enum MutationError<'trg, 'm> {
     DummySubsts(&'m Mut<'trg, 'm>, Vec<&'m SubstDef>),
 }
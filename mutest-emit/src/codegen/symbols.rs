@@ -52,6 +52,7 @@ pub mod sym {
         batch_id,
         cargo_package_name,
         cargo_target_kind,
+        content_hash,
         CRATE_KIND,
         display_location,
         display_name,
@@ -120,6 +121,7 @@ pub mod path {
     super::paths! {
         Default (::core::default::Default),
         default (::core::default::Default::default),
+        drop (::core::mem::drop),
         None (::core::option::Option::None),
         Option (::core::option::Option),
         panic (::core::panic),
@@ -53,7 +53,7 @@ impl Idx for EntryPointId {
 }
 
 /// Entry point function to the call graph.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct EntryPoint {
     pub entry_point_id: EntryPointId,
 
@@ -83,7 +83,7 @@ impl Idx for CalleeId {
 }
 
 /// Callee function of either another callee or an entry point within the call graph.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Callee {
     pub callee_id: CalleeId,
 
@@ -109,7 +109,7 @@ pub struct CallInstance {
 }
 
 /// Call graph of multiple entry points.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct CallGraph {
     /// The kind of entry points the call graph was generated for.
     pub entry_points_kind: EntryPointsKind,
@@ -120,7 +120,7 @@ pub struct CallGraph {
 }
 
 /// Information about the crate's tests' call graph.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct CallGraphInfo {
     /// Format version header.
     pub format_version: u32,
@@ -175,6 +175,15 @@ impl<I: Idx, T: Clone> Clone for IdxVec<I, T> {
     }
 }
 
+impl<I: Idx, T: PartialEq> PartialEq for IdxVec<I, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<I: Idx, T: Eq> Eq for IdxVec<I, T> {}
+
 impl<I: Idx, T> Deref for IdxVec<I, T> {
     type Target = IdxSlice<I, T>;
 
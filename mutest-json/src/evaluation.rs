@@ -54,7 +54,7 @@ pub struct MutationDetectionStats {
     pub undetected_mutations_count: usize,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub enum MutationDetection {
     NotRun,
     Detected,
@@ -63,7 +63,7 @@ pub enum MutationDetection {
     Crashed,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Eq, PartialEq, Debug)]
 pub struct MutationDetections(pub IdxVec<MutationId, MutationDetection>);
 
 impl Serialize for MutationDetections {
@@ -120,7 +120,7 @@ impl<'de> Deserialize<'de> for MutationDetections {
 /// The detections are encoded in a simple string format,
 /// with each mutation's detection being depicted by a corresponding ASCII character,
 /// similar to how they are printed to stdout.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct MutationDetectionMatrix {
     /// Overall detection of mutations across all evaluated tests.
     pub overall_detections: MutationDetections,
@@ -128,8 +128,21 @@ pub struct MutationDetectionMatrix {
     pub test_detections: IdxVec<RuntimeTestId, MutationDetections>,
 }
 
+/// Details of a test crash caused by a mutation.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MutationCrash {
+    /// Mutation that was active when the test crashed.
+    pub mutation_id: MutationId,
+    /// Test that crashed.
+    pub runtime_test_id: RuntimeTestId,
+    /// Description of the crash, such as the signal received by the test process.
+    pub message: String,
+    /// Captured output of the crashed test process, including any printed backtraces.
+    pub output: String,
+}
+
 /// Results of a mutation run.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct MutationRun {
     /// Detection statistics about all mutations.
     pub all_mutations_detection_stats: MutationDetectionStats,
@@ -142,6 +155,11 @@ pub struct MutationRun {
 
     /// Test--mutation detection matrix.
     pub mutation_detection_matrix: MutationDetectionMatrix,
+    /// Details of test crashes caused by mutations.
+    ///
+    /// Not present in JSON data written by older versions of mutest-rs.
+    #[serde(default)]
+    pub crashes: Vec<MutationCrash>,
 
     /// Time it took to perform the mutation run.
     pub duration: Duration,
@@ -153,7 +171,7 @@ pub struct MutationRun {
 /// The detection flakiness is encoded in a simple string format,
 /// with each mutation's detection flakiness being depicted by a corresponding ASCII character,
 /// similar to how they are printed to stdout.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct MutationFlakinessMatrix {
     /// Overall detection flakiness of mutations across all evaluated tests.
     pub overall_detection_flakiness: String,
@@ -162,7 +180,7 @@ pub struct MutationFlakinessMatrix {
 }
 
 /// Analysis of flakiness in test--mutation detections based on repeated mutation runs.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct MutationFlakinessAnalysis {
     /// Test--mutation detection flakiness matrix.
     pub mutation_flakiness_matrix: MutationFlakinessMatrix,
@@ -171,7 +189,7 @@ pub struct MutationFlakinessAnalysis {
 }
 
 /// Information about the mutation evaluation.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct EvaluationInfo {
     /// Format version header.
     pub format_version: u32,
@@ -190,3 +208,26 @@ pub struct EvaluationInfo {
     /// Total time it took to evaluate the mutations.
     pub duration: Duration,
 }
+
+/// Evaluated mutation, selected by its detection status.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct FilteredMutation {
+    pub mutation_id: MutationId,
+    pub mutation_op: String,
+    pub display_name: String,
+    pub display_location: String,
+    /// Overall detection status of the mutation.
+    pub detection: MutationDetection,
+}
+
+/// Evaluated mutations with the selected detection statuses, e.g. only the undetected mutations.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct FilteredMutationsInfo {
+    /// Format version header.
+    pub format_version: u32,
+
+    /// Detection statuses the mutations were selected by.
+    pub detections: Vec<MutationDetection>,
+    /// Selected mutations, in order of their mutation IDs.
+    pub mutations: Vec<FilteredMutation>,
+}
@@ -1,9 +1,10 @@
+use std::collections::HashMap;
 use std::num::NonZeroU64;
 use std::time::Duration;
 
 use serde::{Serialize, Deserialize};
 
-use crate::mutations::MutationId;
+use crate::mutations::{MutationHash, MutationId};
 
 /// Whole number of nanoseconds.
 /// Used in timestamps and measured execution times.
@@ -19,14 +20,14 @@ impl Nanos {
 /// Mutation evaluation stream header.
 ///
 /// This is always the first message in the stream.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct EvaluationStreamHeader {
     /// Format version header.
     pub format_version: u32,
 }
 
 /// Event corresponding to the start of a test case's execution.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct TestStartEvent {
     /// Event timestamp.
     pub time: Nanos,
@@ -58,7 +59,7 @@ pub enum TestResult {
 ///
 /// **NOTE**: For timed out lingering tests, we dispatch two [`Event::TestResult`] events;
 ///           one for the timeout, and one after eventual completion or termination.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct TestResultEvent {
     /// Event timestamp.
     pub time: Nanos,
@@ -73,8 +74,69 @@ pub struct TestResultEvent {
     pub test_result: TestResult,
 }
 
+/// Detection result of an evaluated mutation.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MutationResult {
+    /// Mutation detected by a failing test.
+    Detected,
+    /// Mutation not detected by any test.
+    Undetected,
+    /// Mutation detected by a test timing out.
+    TimedOut,
+    /// Mutation detected by a test crashing.
+    Crashed,
+}
+
+/// Details of a test crash caused by an evaluated mutation.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MutationResultCrash {
+    /// Name of the test that crashed.
+    pub test_name: String,
+    /// Description of the crash, such as the signal received by the test process.
+    pub message: String,
+    /// Captured output of the crashed test process.
+    pub output: String,
+}
+
+/// Event corresponding to the completed evaluation of a mutation.
+///
+/// These events are only written to evaluation checkpoints, which
+/// are used to resume interrupted mutation evaluations.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MutationResultEvent {
+    /// Event timestamp.
+    pub time: Nanos,
+    /// Evaluated mutation.
+    pub mutation_id: MutationId,
+    /// Content hash of the evaluated mutation, which identifies the mutation across compilations.
+    ///
+    /// Not present in checkpoints written by older versions of mutest-rs.
+    pub content_hash: Option<MutationHash>,
+    /// Hash of the test binary that evaluated the mutation.
+    /// Results are only valid for the same test binary, as changes to the tests also affect the results.
+    ///
+    /// Not present in checkpoints written by older versions of mutest-rs.
+    pub test_binary_hash: Option<String>,
+    /// Name of the mutation operator that generated the mutation.
+    pub mutation_op: String,
+    /// User-facing, descriptive name of the mutation.
+    pub display_name: String,
+    /// User-facing location of the mutation.
+    pub display_location: String,
+    /// Overall result of the mutation.
+    pub mutation_result: MutationResult,
+    /// Results of the individual tests evaluated against the mutation, keyed by test name.
+    pub test_results: HashMap<String, MutationResult>,
+    /// Details of test crashes caused by the mutation.
+    ///
+    /// Not present in checkpoints written by older versions of mutest-rs.
+    #[serde(default)]
+    pub crashes: Vec<MutationResultCrash>,
+}
+
 /// Mutation evaluation stream event.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "event")]
 pub enum Event {
@@ -85,4 +147,6 @@ pub enum Event {
     /// **NOTE**: Multiple result events may be dispatched for the same test--mutation pair.
     ///           See [`TestResultEvent`] for more information.
     TestResult(TestResultEvent),
+    /// Event corresponding to the completed evaluation of a mutation.
+    MutationResult(MutationResultEvent),
 }
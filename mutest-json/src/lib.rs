@@ -1,6 +1,12 @@
 #![cfg_attr(feature = "rustc", feature(rustc_private))]
 
+use std::error::Error;
+use std::fmt;
+use std::iter;
+use std::num::ParseIntError;
+use std::ops::Range;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use serde::{Serialize, Deserialize};
 
@@ -25,6 +31,47 @@ pub struct Span {
     pub path: PathBuf,
     pub begin: (usize, usize),
     pub end: (usize, usize),
+    /// Absolute byte offsets of the beginning and end of the span in the file.
+    ///
+    /// Not present in JSON data written by older versions of mutest-rs.
+    pub bytes: Option<(usize, usize)>,
+}
+
+impl Span {
+    /// Computes the absolute byte range of the span in the given source text of the file.
+    ///
+    /// The explicit byte offsets of the span are used, if present.
+    /// Otherwise, the byte range is computed from the lines and columns of the span,
+    /// which are 1-based, and where columns are counted in characters, not bytes.
+    /// Returns `None` if the span does not lie within the source text.
+    pub fn byte_range(&self, source: &str) -> Option<Range<usize>> {
+        if let Some((begin, end)) = self.bytes {
+            if end < begin || !source.is_char_boundary(begin) || !source.is_char_boundary(end) { return None; }
+            return Some(begin..end);
+        }
+
+        let begin = byte_offset(source, self.begin)?;
+        let end = byte_offset(source, self.end)?;
+        if end < begin { return None; }
+
+        Some(begin..end)
+    }
+}
+
+fn byte_offset(source: &str, (line, col): (usize, usize)) -> Option<usize> {
+    let line_start = match line {
+        0 => return None,
+        // NOTE: The BOM is not part of the first line, matching how rustc counts columns.
+        1 => if source.starts_with('\u{feff}') { '\u{feff}'.len_utf8() } else { 0 },
+        _ => source.match_indices('\n').nth(line - 2)?.0 + 1,
+    };
+    let line_str = source[line_start..].split('\n').next().unwrap_or_default();
+
+    let col_offset = line_str.char_indices().map(|(i, _)| i)
+        .chain(iter::once(line_str.len()))
+        .nth(col.checked_sub(1)?)?;
+
+    Some(line_start + col_offset)
 }
 
 #[cfg(feature = "rustc")]
@@ -35,7 +82,14 @@ impl Span {
         let rustc_span::FileName::Real(file_name) = &source_file.name else { return None; };
         let path = file_name.local_path()?.to_owned();
 
-        Some(Self { path, begin: (begin_line, begin_col), end: (end_line, end_col) })
+        // NOTE: rustc normalizes the source text of files (e.g. by removing the BOM, and converting CRLF line endings to LF),
+        //       so we compute the byte offsets in the original source text, as it is on disk.
+        let bytes = (
+            source_file.original_relative_byte_pos(span.lo()).0 as usize,
+            source_file.original_relative_byte_pos(span.hi()).0 as usize,
+        );
+
+        Some(Self { path, begin: (begin_line, begin_col), end: (end_line, end_col), bytes: Some(bytes) })
     }
 }
 
@@ -46,7 +100,35 @@ pub enum Safety {
     Unsafe,
 }
 
+/// Error returned when parsing an ID from a string fails.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ParseIdError {
+    /// The string is not a valid integer.
+    InvalidInt(ParseIntError),
+    /// The integer is not a valid ID value (e.g. zero for 1-based IDs).
+    OutOfRange,
+}
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInt(err) => write!(f, "invalid id: {err}"),
+            Self::OutOfRange => write!(f, "invalid id: out of range"),
+        }
+    }
+}
+
+impl Error for ParseIdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidInt(err) => Some(err),
+            Self::OutOfRange => None,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct DefId(pub u32);
 
 impl Idx for DefId {
@@ -59,6 +141,20 @@ impl Idx for DefId {
     }
 }
 
+impl fmt::Display for DefId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for DefId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u32>().map(Self).map_err(ParseIdError::InvalidInt)
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub struct Definition {
     pub def_id: DefId,
@@ -73,3 +169,144 @@ pub mod evaluation_stream;
 pub mod tests;
 pub mod timings;
 pub mod mutations;
+
+#[cfg(test)]
+mod serde_tests;
+
+#[cfg(test)]
+mod id_tests {
+    use crate::{DefId, Idx, IdxVec, ParseIdError};
+    use crate::mutations::MutationId;
+
+    #[test]
+    fn test_def_id_display_from_str() {
+        assert_eq!(DefId(0).to_string(), "0");
+        assert_eq!(DefId(42).to_string(), "42");
+        assert_eq!("0".parse::<DefId>(), Ok(DefId(0)));
+        assert_eq!("42".parse::<DefId>(), Ok(DefId(42)));
+        assert!(matches!("".parse::<DefId>(), Err(ParseIdError::InvalidInt(_))));
+        assert!(matches!("-1".parse::<DefId>(), Err(ParseIdError::InvalidInt(_))));
+        assert!(matches!("4x".parse::<DefId>(), Err(ParseIdError::InvalidInt(_))));
+    }
+
+    #[test]
+    fn test_mutation_id_display_from_str() {
+        assert_eq!(MutationId(1).to_string(), "1");
+        assert_eq!("1".parse::<MutationId>(), Ok(MutationId(1)));
+        assert_eq!("137".parse::<MutationId>(), Ok(MutationId(137)));
+        // Mutation IDs are 1-based.
+        assert_eq!("0".parse::<MutationId>(), Err(ParseIdError::OutOfRange));
+        assert!(matches!("one".parse::<MutationId>(), Err(ParseIdError::InvalidInt(_))));
+    }
+
+    #[test]
+    fn test_mutation_id_index_mapping() {
+        assert_eq!(MutationId(1).as_index(), 0);
+        assert_eq!(MutationId(137).as_index(), 136);
+        assert_eq!(MutationId::from_index(0), MutationId(1));
+        assert_eq!(MutationId::from_index(MutationId(42).as_index()), MutationId(42));
+
+        let mut mutation_names = IdxVec::<MutationId, &str>::new();
+        mutation_names.extend(["first", "second", "third"]);
+        // The displayed ID differs from the index of the mutation in the collection.
+        let mutation_id = MutationId(2);
+        assert_eq!(mutation_id.to_string(), "2");
+        assert_eq!(mutation_names[mutation_id], "second");
+    }
+
+    #[test]
+    fn test_ids_serialize_transparently() {
+        assert_eq!(serde_json::to_string(&DefId(7)).unwrap(), "7");
+        assert_eq!(serde_json::to_string(&MutationId(7)).unwrap(), "7");
+        assert_eq!(serde_json::from_str::<MutationId>("7").unwrap(), MutationId(7));
+    }
+}
+
+#[cfg(test)]
+mod span_tests {
+    use std::path::PathBuf;
+
+    use super::Span;
+
+    fn span(begin: (usize, usize), end: (usize, usize)) -> Span {
+        Span { path: PathBuf::from("src/lib.rs"), begin, end, bytes: None }
+    }
+
+    #[test]
+    fn test_byte_range_single_line() {
+        let source = "fn f() {\n    let x = 5u32;\n}\n";
+        assert_eq!(span((2, 13), (2, 17)).byte_range(source), Some(21..25));
+        assert_eq!(&source[21..25], "5u32");
+    }
+
+    #[test]
+    fn test_byte_range_multi_line() {
+        let source = "fn f() {\n    let x = 5u32;\n}\n";
+        assert_eq!(span((1, 1), (3, 2)).byte_range(source), Some(0..source.len() - 1));
+    }
+
+    #[test]
+    fn test_byte_range_end_of_line() {
+        let source = "let a = 1;\nlet b = 2;";
+        assert_eq!(span((1, 11), (2, 1)).byte_range(source), Some(10..11));
+        assert_eq!(span((2, 1), (2, 11)).byte_range(source), Some(11..21));
+    }
+
+    #[test]
+    fn test_byte_range_multibyte_chars() {
+        let source = "let s = \"héllo\"; let n = 1;";
+        // Columns are counted in characters, so the `é` only moves the byte offsets after it by one.
+        assert_eq!(span((1, 9), (1, 16)).byte_range(source), Some(8..16));
+        assert_eq!(&source[8..16], "\"héllo\"");
+        assert_eq!(span((1, 26), (1, 27)).byte_range(source), Some(26..27));
+        assert_eq!(&source[26..27], "1");
+    }
+
+    #[test]
+    fn test_byte_range_crlf_and_bom() {
+        let source = "\u{feff}fn f() {\r\n    let x = 5u32;\r\n}\r\n";
+        // The BOM does not count towards the columns of the first line.
+        assert_eq!(span((1, 1), (1, 3)).byte_range(source), Some(3..5));
+        assert_eq!(&source[3..5], "fn");
+        // CRLF line endings only shift the byte offsets of subsequent lines.
+        assert_eq!(span((2, 13), (2, 17)).byte_range(source), Some(25..29));
+        assert_eq!(&source[25..29], "5u32");
+
+        // Explicit byte offsets are relative to the original source text, including the BOM and CRLF line endings.
+        let explicit_span = Span { bytes: Some((25, 29)), ..span((2, 13), (2, 17)) };
+        assert_eq!(explicit_span.byte_range(source), Some(25..29));
+    }
+
+    #[test]
+    fn test_byte_range_explicit_bytes() {
+        let source = "let s = \"héllo\"; let n = 1;";
+        let explicit_span = Span { bytes: Some((8, 16)), ..span((1, 9), (1, 16)) };
+        assert_eq!(explicit_span.byte_range(source), Some(8..16));
+
+        // Explicit byte offsets take precedence over lines and columns.
+        let explicit_span = Span { bytes: Some((26, 27)), ..span((1, 1), (1, 2)) };
+        assert_eq!(explicit_span.byte_range(source), Some(26..27));
+
+        // Byte offsets must lie on character boundaries within the source text.
+        assert_eq!(Span { bytes: Some((11, 12)), ..span((1, 1), (1, 2)) }.byte_range(source), None);
+        assert_eq!(Span { bytes: Some((26, 100)), ..span((1, 1), (1, 2)) }.byte_range(source), None);
+        assert_eq!(Span { bytes: Some((16, 8)), ..span((1, 1), (1, 2)) }.byte_range(source), None);
+    }
+
+    #[test]
+    fn test_deserialize_without_bytes() {
+        let span = serde_json::from_str::<Span>(r#"{"path":"src/lib.rs","begin":[1,9],"end":[1,16]}"#).unwrap();
+        assert_eq!(span.bytes, None);
+        assert_eq!(span.byte_range("let s = \"héllo\"; let n = 1;"), Some(8..16));
+    }
+
+    #[test]
+    fn test_byte_range_out_of_bounds() {
+        let source = "let a = 1;\n";
+        assert_eq!(span((0, 1), (1, 2)).byte_range(source), None);
+        assert_eq!(span((1, 0), (1, 2)).byte_range(source), None);
+        assert_eq!(span((1, 1), (1, 12)).byte_range(source), None);
+        assert_eq!(span((1, 1), (3, 1)).byte_range(source), None);
+        assert_eq!(span((1, 5), (1, 2)).byte_range(source), None);
+    }
+}
@@ -1,10 +1,13 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
 use std::time::Duration;
 
 use serde::{Serialize, Deserialize};
 use smallvec::SmallVec;
 
-use crate::{DefId, Span};
+use crate::{DefId, ParseIdError, Span};
 use crate::data_structures::{Idx, IdxVec};
 
 /// Statistics about the crate's mutations.
@@ -61,7 +64,10 @@ pub struct MutationOpStats {
 ///
 /// Mutation IDs are 1-based, so their corresponding indices are
 /// one less than the value of the ID itself.
+/// The value of the ID is what should be displayed to users (see the [`fmt::Display`] implementation),
+/// while [`Idx::as_index`] must be used for indexing collections of mutations (e.g. through [`IdxVec`]).
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct MutationId(pub u32);
 
 impl Idx for MutationId {
@@ -74,6 +80,23 @@ impl Idx for MutationId {
     }
 }
 
+impl fmt::Display for MutationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for MutationId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<u32>().map_err(ParseIdError::InvalidInt)? {
+            0 => Err(ParseIdError::OutOfRange),
+            id => Ok(Self(id)),
+        }
+    }
+}
+
 /// The location of a mutation's code substitution, describing
 /// where in the original code, and how must the code substiution be applied.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
@@ -172,7 +195,7 @@ pub struct EntryPointAssociation {
 
 /// Reachable, mutable definition in which mutation operators were invoked in
 /// to generate program mutations.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Target {
     pub target_id: TargetId,
 
@@ -188,6 +211,87 @@ pub struct Target {
     pub reachable_from: HashMap<String, EntryPointAssociation>,
 }
 
+/// Content-based identity of a mutation, used to match mutations across separate runs,
+/// independently of the order in which mutations were generated.
+///
+/// The hash is computed from
+/// * the path of the file containing the origin span of the mutation,
+/// * the definition path of the target containing the mutation,
+/// * the name of the mutation operator that generated the mutation,
+/// * the location kind, substitute kind, and replacement code of each substitution of the mutation, and
+/// * the occurrence index of the mutation amongst identical mutations within the same target.
+///
+/// The line and column positions of the mutation are deliberately not part of the hash,
+/// so that it is not affected by unrelated changes elsewhere in the file.
+/// Instead, identical mutations within the same target (e.g. the same operator swap in two identical expressions)
+/// are told apart by their occurrence index, counted in source order, so that each mutation has a unique hash.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MutationHash(pub String);
+
+/// 64-bit FNV-1a hasher.
+///
+/// We use FNV-1a, as it is simple and stable across platforms and Rust versions,
+/// unlike the hashers of the standard library.
+struct Fnv1aHasher(u64);
+
+impl Fnv1aHasher {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    /// Writes the bytes of a field, followed by the `0xff` separator byte, which cannot appear in UTF-8 strings.
+    fn write_field(&mut self, bytes: &[u8]) {
+        for &byte in bytes.iter().chain(&[0xff]) {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+impl MutationHash {
+    /// Computes the hash of a mutation.
+    ///
+    /// The `occurrence` is the 0-based index of the mutation amongst the identical mutations preceding it
+    /// in the same target (i.e. mutations with the same hash computed with an occurrence of zero).
+    pub fn compute(origin_path: &Path, target_def_path: &str, mutation_op: &str, substs: &[Substitution], occurrence: usize) -> Self {
+        let mut hasher = Fnv1aHasher::new();
+
+        hasher.write_field(origin_path.to_string_lossy().as_bytes());
+        hasher.write_field(target_def_path.as_bytes());
+        hasher.write_field(mutation_op.as_bytes());
+        for subst in substs {
+            hasher.write_field(match subst.location {
+                SubstitutionLocation::InsertBefore(_) => b"insert_before",
+                SubstitutionLocation::InsertAfter(_) => b"insert_after",
+                SubstitutionLocation::Replace(_) => b"replace",
+            });
+            hasher.write_field(match subst.substitute.kind {
+                SubstituteKind::Expr => b"expr",
+                SubstituteKind::Stmt => b"stmt",
+                SubstituteKind::Local => b"local",
+            });
+            hasher.write_field(subst.substitute.replacement.as_bytes());
+        }
+        // NOTE: The first occurrence is not part of the hash, so that the hashes of mutations
+        //       without identical counterparts are not affected by the disambiguation.
+        if occurrence > 0 {
+            hasher.write_field(occurrence.to_string().as_bytes());
+        }
+
+        Self(format!("{:016x}", hasher.0))
+    }
+}
+
+impl fmt::Display for MutationHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// A program mutation.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
 pub struct Mutation {
@@ -210,6 +314,11 @@ pub struct Mutation {
     /// Mutation safety property of the mutation, denoting
     /// whether the mutation may cause undefined behavior.
     pub safety: MutationSafety,
+
+    /// Content-based identity of the mutation, for matching mutations across runs.
+    ///
+    /// Not present in JSON data written by older versions of mutest-rs.
+    pub content_hash: Option<MutationHash>,
 }
 
 /// Mutation batch ID.
@@ -239,7 +348,7 @@ pub struct MutationBatch {
 }
 
 /// Information about the crate's mutations.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct MutationsInfo {
     /// Format version header.
     pub format_version: u32,
@@ -257,6 +366,77 @@ pub struct MutationsInfo {
     /// Reachable, mutable definitions referred to by the generated mutations.
     pub targets: IdxVec<TargetId, Target>,
 
+    /// Content hashes of the mutations which were generated, but excluded from the run,
+    /// because they were listed in an ignore file.
+    ///
+    /// Not present if no ignore file was specified, or in JSON data written by older versions of mutest-rs.
+    pub ignored_mutations: Option<Vec<MutationHash>>,
+
     /// Time it took to generate the mutations.
     pub duration: Duration,
 }
+
+#[cfg(test)]
+mod hash_tests {
+    use std::path::{Path, PathBuf};
+    use std::slice;
+
+    use crate::Span;
+    use super::{MutationHash, Substitute, SubstituteKind, Substitution, SubstitutionLocation};
+
+    fn replace_subst(begin: (usize, usize), end: (usize, usize), replacement: &str) -> Substitution {
+        Substitution {
+            location: SubstitutionLocation::Replace(Span { path: PathBuf::from("src/lib.rs"), begin, end, bytes: None }),
+            substitute: Substitute { kind: SubstituteKind::Expr, replacement: replacement.to_owned() },
+        }
+    }
+
+    #[test]
+    fn test_mutation_hash_is_stable() {
+        let substs = [replace_subst((2, 5), (2, 10), "a - b")];
+        let hash = MutationHash::compute(Path::new("src/lib.rs"), "add", "math_op_add_sub_swap", &substs, 0);
+        assert_eq!(hash, MutationHash("985aeaa27a5ade35".to_owned()));
+    }
+
+    #[test]
+    fn test_mutation_hash_independent_of_generation_order_and_position() {
+        let mutations = [
+            ("add", "math_op_add_sub_swap", replace_subst((2, 5), (2, 10), "a - b")),
+            ("add", "math_op_add_mul_swap", replace_subst((2, 5), (2, 10), "a * b")),
+            ("sub", "math_op_add_sub_swap", replace_subst((6, 5), (6, 10), "a + b")),
+        ];
+        let hashes = mutations.iter()
+            .map(|(target_def_path, mutation_op, subst)| MutationHash::compute(Path::new("src/lib.rs"), target_def_path, mutation_op, slice::from_ref(subst), 0))
+            .collect::<Vec<_>>();
+
+        // Mutations generated in reverse order, after an unrelated line was inserted above them.
+        let shifted_hashes = mutations.iter().rev()
+            .map(|(target_def_path, mutation_op, subst)| {
+                let SubstitutionLocation::Replace(span) = &subst.location else { unreachable!() };
+                let shifted_subst = replace_subst((span.begin.0 + 1, span.begin.1), (span.end.0 + 1, span.end.1), &subst.substitute.replacement);
+                MutationHash::compute(Path::new("src/lib.rs"), target_def_path, mutation_op, &[shifted_subst], 0)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(hashes, shifted_hashes.into_iter().rev().collect::<Vec<_>>());
+
+        // Distinct mutations have distinct hashes.
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_ne!(hashes[1], hashes[2]);
+    }
+
+    #[test]
+    fn test_mutation_hash_distinguishes_identical_mutations() {
+        let first_subst = replace_subst((2, 5), (2, 10), "a - b");
+        let second_subst = replace_subst((3, 5), (3, 10), "a - b");
+
+        let first_hash = MutationHash::compute(Path::new("src/lib.rs"), "add", "math_op_add_sub_swap", slice::from_ref(&first_subst), 0);
+        let second_hash = MutationHash::compute(Path::new("src/lib.rs"), "add", "math_op_add_sub_swap", slice::from_ref(&second_subst), 1);
+        assert_ne!(first_hash, second_hash);
+
+        // The occurrence index, unlike the position, is part of the hash.
+        let shifted_second_subst = replace_subst((4, 5), (4, 10), "a - b");
+        assert_eq!(second_hash, MutationHash::compute(Path::new("src/lib.rs"), "add", "math_op_add_sub_swap", &[shifted_second_subst], 1));
+    }
+}
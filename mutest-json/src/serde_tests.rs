@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::num::NonZeroU64;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Serialize, Deserialize};
+use smallvec::smallvec;
+
+use crate::{DefId, Definition, FORMAT_VERSION, Safety, Span};
+use crate::call_graph::*;
+use crate::data_structures::IdxVec;
+use crate::evaluation::*;
+use crate::evaluation_stream::*;
+use crate::mutations::*;
+use crate::tests::*;
+use crate::timings::*;
+
+fn assert_round_trip<T>(value: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    let json = serde_json::to_string(value).expect("cannot serialize value");
+    let deserialized_value: T = serde_json::from_str(&json).expect("cannot deserialize value");
+    assert_eq!(value, &deserialized_value, "value does not round-trip through JSON: {json}");
+}
+
+fn span(begin: (usize, usize), end: (usize, usize)) -> Span {
+    Span { path: PathBuf::from("src/lib.rs"), begin, end, bytes: Some((begin.0 * 100 + begin.1, end.0 * 100 + end.1)) }
+}
+
+fn idx_vec<I: crate::Idx, T>(values: impl IntoIterator<Item = T>) -> IdxVec<I, T> {
+    let mut idx_vec = IdxVec::new();
+    idx_vec.extend(values);
+    idx_vec
+}
+
+#[test]
+fn test_tests_info_round_trip() {
+    assert_round_trip(&TestsInfo {
+        format_version: FORMAT_VERSION,
+        stats: TestStats { total_tests_count: 2, ignored_tests_count: 1 },
+        tests: vec![
+            Test { name: "tests::test_a".to_owned(), span: span((10, 1), (13, 2)), ignore: false },
+            Test { name: "tests::test_b".to_owned(), span: span((16, 1), (19, 2)), ignore: true },
+        ],
+        duration: Duration::from_micros(1234),
+    });
+}
+
+#[test]
+fn test_call_graph_info_round_trip() {
+    let call_instance = CallInstance { span: Some(span((3, 5), (3, 12))), safety: Safety::Safe };
+    let unsafe_call_instance = CallInstance { span: None, safety: Safety::Unsafe };
+
+    assert_round_trip(&CallGraphInfo {
+        format_version: FORMAT_VERSION,
+        stats: CallGraphStats {
+            all_mutable_fns_count: 3,
+            reachable_fns_count: 2,
+            total_calls_count: 3,
+            virtual_calls_count: 0,
+            dynamic_calls_count: 1,
+            foreign_calls_count: 0,
+            call_graph_depth: 2,
+        },
+        call_graph: CallGraph {
+            entry_points_kind: EntryPointsKind::Tests,
+            entry_points: idx_vec([
+                EntryPoint {
+                    entry_point_id: EntryPointId(0),
+                    name: "test_a".to_owned(),
+                    path: "tests::test_a".to_owned(),
+                    span: Some(span((10, 1), (13, 2))),
+                    calls: HashMap::from_iter([(CalleeId(0), smallvec![call_instance.clone(), call_instance.clone()])]),
+                },
+            ]),
+            callees: idx_vec([
+                Callee {
+                    callee_id: CalleeId(0),
+                    def_id: DefId(0),
+                    generic_args: vec![],
+                    path_with_generic_args: "f".to_owned(),
+                    calls: HashMap::from_iter([(CalleeId(1), smallvec![unsafe_call_instance])]),
+                },
+                Callee {
+                    callee_id: CalleeId(1),
+                    def_id: DefId(1),
+                    generic_args: vec!["u32".to_owned()],
+                    path_with_generic_args: "g::<u32>".to_owned(),
+                    calls: HashMap::new(),
+                },
+            ]),
+        },
+        definitions: idx_vec([
+            Definition { def_id: DefId(0), name: Some("f".to_owned()), path: Some("f".to_owned()), span: Some(span((1, 1), (5, 2))) },
+            Definition { def_id: DefId(1), name: None, path: None, span: None },
+        ]),
+        duration: Duration::from_millis(56),
+    });
+}
+
+#[test]
+fn test_mutations_info_round_trip() {
+    let mutation = |mutation_id, location, safety| Mutation {
+        mutation_id: MutationId(mutation_id),
+        target_id: TargetId(0),
+        origin_span: span((3, 5), (3, 10)),
+        mutation_op: "op_swap".to_owned(),
+        display_name: "swap `+` for `-`".to_owned(),
+        substs: smallvec![
+            Substitution {
+                location,
+                substitute: Substitute { kind: SubstituteKind::Expr, replacement: "a - b".to_owned() },
+            },
+        ],
+        safety,
+        content_hash: Some(MutationHash(format!("{mutation_id:016x}"))),
+    };
+
+    assert_round_trip(&MutationsInfo {
+        format_version: FORMAT_VERSION,
+        stats: MutationStats {
+            all_mutable_fns_count: 3,
+            mutated_fns_count: 1,
+            total_mutations_count: 3,
+            safe_mutations_count: 1,
+            unsafe_mutations_count: 1,
+            tainted_mutations_count: 1,
+            mutation_conflicts_count: 1,
+            mutation_conflicts_count_excluding_unsafe: 0,
+            mutation_compatibilities_count: 2,
+            batched_mutations_count: 2,
+            unbatched_mutations_count: 1,
+        },
+        per_op_stats: HashMap::from_iter([
+            ("op_swap".to_owned(), MutationOpStats { total_mutations_count: 3, ..Default::default() }),
+        ]),
+        mutations: idx_vec([
+            mutation(1, SubstitutionLocation::Replace(span((3, 5), (3, 10))), MutationSafety::Safe),
+            mutation(2, SubstitutionLocation::InsertBefore(span((4, 5), (4, 10))), MutationSafety::Tainted),
+            mutation(3, SubstitutionLocation::InsertAfter(span((5, 5), (5, 10))), MutationSafety::Unsafe),
+        ]),
+        mutation_batches: Some(idx_vec([
+            MutationBatch { mutation_batch_id: MutationBatchId(1), mutation_ids: smallvec![MutationId(1), MutationId(2)] },
+            MutationBatch { mutation_batch_id: MutationBatchId(2), mutation_ids: smallvec![MutationId(3)] },
+        ])),
+        targets: idx_vec([
+            Target {
+                target_id: TargetId(0),
+                def_id: DefId(0),
+                safety: MutationSafety::Tainted,
+                reachability: TargetReachability::NestedCallee { distance: 1 },
+                reachable_from: HashMap::from_iter([
+                    ("tests::test_a".to_owned(), EntryPointAssociation { distance: 1, tainted_call_path: true }),
+                ]),
+            },
+            Target {
+                target_id: TargetId(1),
+                def_id: DefId(1),
+                safety: MutationSafety::Safe,
+                reachability: TargetReachability::DirectEntryPoint,
+                reachable_from: HashMap::new(),
+            },
+        ]),
+        ignored_mutations: Some(vec![MutationHash("92c92add98bca27f".to_owned())]),
+        duration: Duration::from_nanos(987_654_321),
+    });
+}
+
+#[test]
+fn test_mutation_detections_round_trip() {
+    assert_round_trip(&MutationDetections(IdxVec::new()));
+    assert_round_trip(&MutationDetections(idx_vec([
+        MutationDetection::NotRun,
+        MutationDetection::Detected,
+        MutationDetection::Undetected,
+        MutationDetection::TimedOut,
+        MutationDetection::Crashed,
+    ])));
+
+    assert_eq!(serde_json::to_string(&MutationDetections(idx_vec([MutationDetection::Detected, MutationDetection::Undetected]))).unwrap(), "\"D-\"");
+    assert!(serde_json::from_str::<MutationDetections>("\"DX\"").is_err());
+}
+
+#[test]
+fn test_evaluation_info_round_trip() {
+    let detection_stats = MutationDetectionStats {
+        mutation_score: Some(0.5),
+        total_mutations_count: 2,
+        detected_mutations_count: 1,
+        timed_out_mutations_count: 0,
+        crashed_mutations_count: 0,
+        undetected_mutations_count: 1,
+    };
+    let empty_detection_stats = MutationDetectionStats {
+        mutation_score: None,
+        total_mutations_count: 0,
+        detected_mutations_count: 0,
+        timed_out_mutations_count: 0,
+        crashed_mutations_count: 0,
+        undetected_mutations_count: 0,
+    };
+
+    assert_round_trip(&EvaluationInfo {
+        format_version: FORMAT_VERSION,
+        mutation_runs: smallvec![
+            MutationRun {
+                all_mutations_detection_stats: detection_stats.clone(),
+                safe_mutations_detection_stats: detection_stats.clone(),
+                unsafe_mutations_detection_stats: empty_detection_stats,
+                per_op_mutation_detection_stats: HashMap::from_iter([("op_swap".to_owned(), detection_stats)]),
+                mutation_detection_matrix: MutationDetectionMatrix {
+                    overall_detections: MutationDetections(idx_vec([MutationDetection::Detected, MutationDetection::Undetected])),
+                    test_detections: idx_vec([
+                        MutationDetections(idx_vec([MutationDetection::Detected, MutationDetection::Undetected])),
+                        MutationDetections(idx_vec([MutationDetection::NotRun, MutationDetection::Undetected])),
+                    ]),
+                },
+                crashes: vec![
+                    MutationCrash {
+                        mutation_id: MutationId(2),
+                        runtime_test_id: RuntimeTestId(1),
+                        message: "received signal 11".to_owned(),
+                        output: "thread 'tests::test_b' panicked at src/lib.rs:4:5:\n".to_owned(),
+                    },
+                ],
+                duration: Duration::from_secs(2),
+            },
+        ],
+        flakiness_analysis: Some(MutationFlakinessAnalysis {
+            mutation_flakiness_matrix: MutationFlakinessMatrix {
+                overall_detection_flakiness: "F.".to_owned(),
+                test_detection_flakiness: idx_vec(["F.".to_owned(), "..".to_owned()]),
+            },
+            duration: Duration::from_secs(6),
+        }),
+        tests: idx_vec([
+            RuntimeTest {
+                runtime_test_id: RuntimeTestId(0),
+                name: "tests::test_a".to_owned(),
+                unmutated_exec_time: Some(Duration::from_micros(150)),
+                timeout: Some(Duration::from_millis(10)),
+            },
+            RuntimeTest {
+                runtime_test_id: RuntimeTestId(1),
+                name: "tests::test_b".to_owned(),
+                unmutated_exec_time: None,
+                timeout: None,
+            },
+        ]),
+        test_profiling_duration: Duration::from_millis(3),
+        duration: Duration::from_secs(8),
+    });
+}
+
+#[test]
+fn test_mutation_run_without_crashes() {
+    let detection_stats = MutationDetectionStats {
+        mutation_score: Some(1.0),
+        total_mutations_count: 1,
+        detected_mutations_count: 1,
+        timed_out_mutations_count: 0,
+        crashed_mutations_count: 0,
+        undetected_mutations_count: 0,
+    };
+    let mutation_run = MutationRun {
+        all_mutations_detection_stats: detection_stats.clone(),
+        safe_mutations_detection_stats: detection_stats.clone(),
+        unsafe_mutations_detection_stats: detection_stats,
+        per_op_mutation_detection_stats: HashMap::new(),
+        mutation_detection_matrix: MutationDetectionMatrix {
+            overall_detections: MutationDetections(idx_vec([MutationDetection::Detected])),
+            test_detections: idx_vec([MutationDetections(idx_vec([MutationDetection::Detected]))]),
+        },
+        crashes: vec![],
+        duration: Duration::from_secs(1),
+    };
+
+    // Mutation runs written by older versions of mutest-rs do not record crashes.
+    let mut json = serde_json::to_value(&mutation_run).expect("cannot serialize value");
+    json.as_object_mut().unwrap().remove("crashes");
+    let json = serde_json::to_string(&json).expect("cannot serialize value");
+    assert_eq!(mutation_run, serde_json::from_str::<MutationRun>(&json).expect("cannot deserialize value"));
+}
+
+#[test]
+fn test_filtered_mutations_info_round_trip() {
+    assert_round_trip(&FilteredMutationsInfo {
+        format_version: FORMAT_VERSION,
+        detections: vec![MutationDetection::Undetected, MutationDetection::TimedOut],
+        mutations: vec![
+            FilteredMutation {
+                mutation_id: MutationId(2),
+                mutation_op: "op_swap".to_owned(),
+                display_name: "swap `+` for `-`".to_owned(),
+                display_location: "src/lib.rs:3:5: 3:10".to_owned(),
+                detection: MutationDetection::Undetected,
+            },
+        ],
+    });
+}
+
+#[test]
+fn test_evaluation_stream_round_trip() {
+    assert_round_trip(&EvaluationStreamHeader { format_version: FORMAT_VERSION });
+
+    assert_round_trip(&Event::TestStart(TestStartEvent {
+        time: Nanos(1_000),
+        mutation_id: MutationId(1),
+        test_name: "tests::test_a".to_owned(),
+        thread_id: NonZeroU64::new(7).unwrap(),
+    }));
+    for test_result in [TestResult::Ok, TestResult::Ignored, TestResult::Failed, TestResult::Crashed, TestResult::TimedOut] {
+        assert_round_trip(&Event::TestResult(TestResultEvent {
+            time: Nanos(2_000),
+            mutation_id: MutationId(1),
+            test_name: "tests::test_a".to_owned(),
+            test_exec_time: (test_result != TestResult::Ignored).then_some(Nanos(1_000)),
+            test_result,
+        }));
+    }
+    assert_round_trip(&Event::MutationResult(MutationResultEvent {
+        time: Nanos(3_000),
+        mutation_id: MutationId(1),
+        content_hash: Some(MutationHash("985aeaa27a5ade35".to_owned())),
+        test_binary_hash: Some("0123456789abcdef".to_owned()),
+        mutation_op: "op_swap".to_owned(),
+        display_name: "swap `+` for `-`".to_owned(),
+        display_location: "src/lib.rs:3:5: 3:10".to_owned(),
+        mutation_result: MutationResult::Crashed,
+        test_results: HashMap::from_iter([
+            ("tests::test_a".to_owned(), MutationResult::Crashed),
+            ("tests::test_b".to_owned(), MutationResult::Undetected),
+        ]),
+        crashes: vec![
+            MutationResultCrash {
+                test_name: "tests::test_a".to_owned(),
+                message: "received signal 11".to_owned(),
+                output: String::new(),
+            },
+        ],
+    }));
+}
+
+#[test]
+fn test_timings_info_round_trip() {
+    assert_round_trip(&TimingsInfo {
+        total_duration: Duration::from_secs(10),
+        analysis_duration: Duration::from_secs(4),
+        test_discovery_duration: Duration::from_millis(100),
+        target_analysis_duration: Duration::from_millis(200),
+        sanitize_macro_expns_duration: Duration::from_millis(300),
+        mutation_generation_duration: Duration::from_millis(400),
+        mutation_conflict_resolution_duration: Duration::from_millis(500),
+        mutation_batching_duration: Duration::from_millis(600),
+        codegen_duration: Duration::from_millis(700),
+        write_duration: Duration::from_millis(800),
+        external_tests_compilation_duration: None,
+        mutant_compilation_duration: Some(Duration::from_secs(5)),
+        total_compilation_duration: Some(Duration::from_secs(5)),
+    });
+}
@@ -3,7 +3,7 @@ use std::time::Duration;
 use serde::{Serialize, Deserialize};
 
 /// Information about the time each stage of the mutation generation process took.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct TimingsInfo {
     /// Total time it took to analyze the crate, generate mutations, and compile the binary.
     pub total_duration: Duration,
@@ -0,0 +1,63 @@
+use mutest_emit::{Mutation, Operator};
+use mutest_emit::analysis::hir;
+use mutest_emit::codegen::ast;
+use mutest_emit::codegen::mutation::{MutCtxt, MutLoc, Mutations, Subst, SubstDef, SubstLoc};
+use mutest_emit::codegen::symbols::kw;
+use mutest_emit::smallvec::smallvec;
+
+pub const BOOL_LIT_COND_FLIP: &str = "bool_lit_cond_flip";
+
+pub struct BoolLitCondFlipMutation {
+    pub original_value: bool,
+}
+
+impl Mutation for BoolLitCondFlipMutation {
+    fn op_name(&self) -> &str { BOOL_LIT_COND_FLIP }
+
+    fn display_name(&self) -> String {
+        format!("replace boolean literal condition `{original_value}` with `{replacement_value}`",
+            original_value = self.original_value,
+            replacement_value = !self.original_value,
+        )
+    }
+
+    fn span_label(&self) -> String {
+        format!("replace boolean literal condition with `{replacement_value}`",
+            replacement_value = !self.original_value,
+        )
+    }
+}
+
+/// Flip boolean literals which are the conditions of `if` and `while` expressions.
+pub struct BoolLitCondFlip;
+
+impl<'a> Operator<'a> for BoolLitCondFlip {
+    type Mutation = BoolLitCondFlipMutation;
+
+    fn try_apply(&self, mcx: &MutCtxt) -> Mutations<Self::Mutation> {
+        let MutCtxt { opts: _, tcx, crate_res: _, def_res: _, def_site: def, item_hir: _, body_res, location } = *mcx;
+
+        let MutLoc::FnBodyExpr(expr, _f) = location else { return Mutations::none(); };
+
+        let ast::ExprKind::Lit(lit) = &expr.kind else { return Mutations::none(); };
+        let ast::token::LitKind::Bool = lit.kind else { return Mutations::none(); };
+        let original_value = lit.symbol == kw::True;
+
+        // NOTE: Both `if` and `while` conditions are lowered into the condition of an `if` expression in the HIR,
+        //       wrapped in a `DropTemps` expression. Parentheses around the condition are not present in the HIR.
+        //       Literals nested deeper in the condition (e.g. `if x && true`) have a different parent.
+        let Some(expr_hir) = body_res.hir_expr(expr) else { unreachable!() };
+        let hir::Node::Expr(drop_temps_hir @ hir::Expr { kind: hir::ExprKind::DropTemps(_), .. }) = tcx.parent_hir_node(expr_hir.hir_id) else { return Mutations::none(); };
+        let hir::Node::Expr(hir::Expr { kind: hir::ExprKind::If(cond_hir, _, _), .. }) = tcx.parent_hir_node(drop_temps_hir.hir_id) else { return Mutations::none(); };
+        if cond_hir.hir_id != drop_temps_hir.hir_id { return Mutations::none(); }
+
+        let flipped_lit = ast::mk::expr_bool(def, !original_value);
+
+        Mutations::new_one(BoolLitCondFlipMutation { original_value }, smallvec![
+            SubstDef::new(
+                SubstLoc::Replace(expr.id, expr.span),
+                Subst::AstExpr(*flipped_lit),
+            ),
+        ])
+    }
+}
@@ -0,0 +1,103 @@
+use mutest_emit::{Mutation, Operator};
+use mutest_emit::analysis::hir;
+use mutest_emit::analysis::res;
+use mutest_emit::analysis::ty;
+use mutest_emit::codegen::ast;
+use mutest_emit::codegen::mutation::{MutCtxt, MutLoc, Mutations, Subst, SubstDef, SubstLoc};
+use mutest_emit::smallvec::smallvec;
+
+pub const CALL_ARG_SWAP: &str = "call_arg_swap";
+
+pub struct CallArgSwapMutation {
+    pub callee_path: String,
+    /// Index of the first argument of the swapped pair of adjacent arguments.
+    pub arg_index: usize,
+}
+
+impl Mutation for CallArgSwapMutation {
+    fn op_name(&self) -> &str { CALL_ARG_SWAP }
+
+    fn display_name(&self) -> String {
+        format!("swap arguments {first} and {second} of call to `{callee}`",
+            first = self.arg_index + 1,
+            second = self.arg_index + 2,
+            callee = self.callee_path,
+        )
+    }
+
+    fn span_label(&self) -> String {
+        format!("swap arguments {first} and {second}",
+            first = self.arg_index + 1,
+            second = self.arg_index + 2,
+        )
+    }
+}
+
+/// Swap adjacent arguments of the same type in function and method calls, to test whether the order of
+/// arguments is meaningfully tested.
+pub struct CallArgSwap;
+
+impl<'a> Operator<'a> for CallArgSwap {
+    type Mutation = CallArgSwapMutation;
+
+    fn try_apply(&self, mcx: &MutCtxt) -> Mutations<Self::Mutation> {
+        let MutCtxt { opts: _, tcx, crate_res: _, def_res: _, def_site: _, item_hir: f_hir, body_res, location } = *mcx;
+
+        let MutLoc::FnBodyExpr(expr, _f) = location else { return Mutations::none(); };
+
+        let args = match &expr.kind {
+            ast::ExprKind::Call(_, args) => args,
+            ast::ExprKind::MethodCall(method_call) => &method_call.args,
+            _ => { return Mutations::none(); }
+        };
+        if args.len() < 2 { return Mutations::none(); }
+
+        let Some(body_hir) = f_hir.body else { return Mutations::none(); };
+        let typeck = tcx.typeck_body(body_hir.id());
+
+        let Some(expr_hir) = body_res.hir_expr(expr) else { unreachable!() };
+        let args_hir = match expr_hir.kind {
+            hir::ExprKind::Call(_, args_hir) => args_hir,
+            hir::ExprKind::MethodCall(_, _, args_hir, _) => args_hir,
+            _ => unreachable!(),
+        };
+
+        let Some((callee, _)) = res::callee(typeck, expr_hir) else { return Mutations::none(); };
+        let callee_path = tcx.def_path_str(callee);
+
+        let typing_env = ty::TypingEnv::post_analysis(tcx, f_hir.owner_id.def_id);
+
+        Mutations::new(args.windows(2).zip(args_hir.windows(2)).enumerate()
+            .filter_map(|(arg_index, (args, args_hir))| {
+                let [arg_a, arg_b] = args else { unreachable!() };
+                let [arg_a_hir, arg_b_hir] = args_hir else { unreachable!() };
+
+                let arg_ty = typeck.expr_ty(arg_a_hir);
+                if tcx.erase_and_anonymize_regions(arg_ty) != tcx.erase_and_anonymize_regions(typeck.expr_ty(arg_b_hir)) { return None; }
+                if arg_ty == tcx.types.unit { return None; }
+
+                // NOTE: Swapping the arguments also swaps their evaluation order. If one of the arguments
+                //       moves a place, then the other argument may end up using the place after it was moved
+                //       (e.g. `f(s.clone(), s)`), so we only swap moved places of `Copy` types.
+                let moves_place = arg_a_hir.is_place_expr(|_| true) || arg_b_hir.is_place_expr(|_| true);
+                if moves_place && !arg_ty.is_copy_modulo_regions(tcx, typing_env) { return None; }
+
+                let mutation = Self::Mutation {
+                    callee_path: callee_path.clone(),
+                    arg_index,
+                };
+
+                Some((mutation, smallvec![
+                    SubstDef::new(
+                        SubstLoc::Replace(arg_a.id, arg_a.span),
+                        Subst::AstExpr((**arg_b).clone()),
+                    ),
+                    SubstDef::new(
+                        SubstLoc::Replace(arg_b.id, arg_b.span),
+                        Subst::AstExpr((**arg_a).clone()),
+                    ),
+                ]))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,98 @@
+use mutest_emit::{Mutation, Operator};
+use mutest_emit::codegen::ast;
+use mutest_emit::codegen::mutation::{MutCtxt, MutLoc, Mutations, Subst, SubstDef, SubstLoc};
+use mutest_emit::codegen::symbols::Symbol;
+use mutest_emit::smallvec::{SmallVec, smallvec};
+
+pub const INT_LIT_BOUNDARY: &str = "int_lit_boundary";
+
+pub struct IntLitBoundaryMutation {
+    pub original_lit: ast::token::Lit,
+    pub replacement_value: isize,
+    pub suffix: Option<Symbol>,
+}
+
+impl IntLitBoundaryMutation {
+    fn replacement_lit_str(&self) -> String {
+        match self.suffix {
+            Some(suffix) => format!("{}{suffix}", self.replacement_value),
+            None => format!("{}", self.replacement_value),
+        }
+    }
+}
+
+impl Mutation for IntLitBoundaryMutation {
+    fn op_name(&self) -> &str { INT_LIT_BOUNDARY }
+
+    fn display_name(&self) -> String {
+        format!("replace integer literal `{original_lit}` with `{replacement_lit}`",
+            original_lit = self.original_lit,
+            replacement_lit = self.replacement_lit_str(),
+        )
+    }
+
+    fn span_label(&self) -> String {
+        format!("replace integer literal with `{replacement_lit}`",
+            replacement_lit = self.replacement_lit_str(),
+        )
+    }
+}
+
+/// Replace integer literals with their adjacent values, and with zero.
+pub struct IntLitBoundary;
+
+impl<'a> Operator<'a> for IntLitBoundary {
+    type Mutation = IntLitBoundaryMutation;
+
+    fn try_apply(&self, mcx: &MutCtxt) -> Mutations<Self::Mutation> {
+        let MutCtxt { opts: _, tcx, crate_res: _, def_res: _, def_site: def, item_hir: f_hir, body_res, location } = *mcx;
+
+        let MutLoc::FnBodyExpr(expr, _f) = location else { return Mutations::none(); };
+
+        let ast::ExprKind::Lit(token_lit) = expr.kind else { return Mutations::none(); };
+        let Ok(ast::LitKind::Int(value, _)) = ast::LitKind::from_token_lit(token_lit) else { return Mutations::none(); };
+        let Ok(value) = isize::try_from(value.get()) else { return Mutations::none(); };
+
+        let Some(body_hir) = f_hir.body else { return Mutations::none(); };
+        let typeck = tcx.typeck_body(body_hir.id());
+
+        let Some(expr_hir) = body_res.hir_expr(expr) else { unreachable!() };
+        let lit_ty = typeck.expr_ty(expr_hir);
+        if !lit_ty.is_integral() { return Mutations::none(); }
+        let Some((_, max_value)) = lit_ty.numeric_min_and_max_as_bits(tcx) else { return Mutations::none(); };
+
+        // NOTE: Integer literals are never negative themselves, negative values are written using the negation
+        //       operator. We only produce replacements which are valid literals of the type on their own.
+        let mut replacement_values = SmallVec::<[isize; 3]>::new();
+        for replacement_value in [value.checked_add(1), value.checked_sub(1), Some(0)] {
+            let Some(replacement_value) = replacement_value else { continue; };
+            if replacement_value < 0 || replacement_value as u128 > max_value { continue; }
+            if replacement_value == value || replacement_values.contains(&replacement_value) { continue; }
+            replacement_values.push(replacement_value);
+        }
+
+        let suffix = token_lit.suffix;
+
+        Mutations::new(replacement_values.into_iter()
+            .map(|replacement_value| {
+                let replacement_expr = match suffix {
+                    Some(suffix) => ast::mk::expr_int_exact(def, replacement_value, suffix),
+                    None => ast::mk::expr_int(def, replacement_value),
+                };
+
+                let mutation = Self::Mutation {
+                    original_lit: token_lit,
+                    replacement_value,
+                    suffix,
+                };
+
+                (mutation, smallvec![
+                    SubstDef::new(
+                        SubstLoc::Replace(expr.id, expr.span),
+                        Subst::AstExpr(*replacement_expr),
+                    ),
+                ])
+            })
+            .collect())
+    }
+}
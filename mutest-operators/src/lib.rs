@@ -8,6 +8,12 @@ pub use arg_default_shadow::*;
 mod bool_expr_negate;
 pub use bool_expr_negate::*;
 
+mod bool_lit_cond_flip;
+pub use bool_lit_cond_flip::*;
+
+mod call_arg_swap;
+pub use call_arg_swap::*;
+
 mod call_ignore;
 pub use call_ignore::*;
 
@@ -17,6 +23,15 @@ pub use continue_break_swap::*;
 mod eq_op_invert;
 pub use eq_op_invert::*;
 
+mod int_lit_boundary;
+pub use int_lit_boundary::*;
+
+mod match_guard_negate;
+pub use match_guard_negate::*;
+
+mod method_call_skip;
+pub use method_call_skip::*;
+
 mod op_swap;
 pub use op_swap::*;
 
@@ -29,6 +44,12 @@ pub use relational_op_eq_swap::*;
 mod relational_op_invert;
 pub use relational_op_invert::*;
 
+mod return_value_default;
+pub use return_value_default::*;
+
+mod try_op_remove;
+pub use try_op_remove::*;
+
 pub const ALL: &[&str] = &[
     ARG_DEFAULT_SHADOW,
     BIT_OP_OR_AND_SWAP,
@@ -36,16 +57,23 @@ pub const ALL: &[&str] = &[
     BIT_OP_SHIFT_DIR_SWAP,
     BIT_OP_XOR_AND_SWAP,
     BOOL_EXPR_NEGATE,
+    BOOL_LIT_COND_FLIP,
+    CALL_ARG_SWAP,
     CALL_DELETE,
     CALL_VALUE_DEFAULT_SHADOW,
     CONTINUE_BREAK_SWAP,
     EQ_OP_INVERT,
+    INT_LIT_BOUNDARY,
     LOGICAL_OP_AND_OR_SWAP,
+    MATCH_GUARD_NEGATE,
     MATH_OP_ADD_MUL_SWAP,
     MATH_OP_ADD_SUB_SWAP,
     MATH_OP_DIV_REM_SWAP,
     MATH_OP_MUL_DIV_SWAP,
+    METHOD_CALL_SKIP,
     RANGE_LIMIT_SWAP,
     RELATIONAL_OP_EQ_SWAP,
     RELATIONAL_OP_INVERT,
+    RETURN_VALUE_DEFAULT,
+    TRY_OP_REMOVE,
 ];
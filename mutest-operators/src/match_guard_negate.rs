@@ -0,0 +1,64 @@
+use mutest_emit::{Mutation, Operator};
+use mutest_emit::analysis::hir;
+use mutest_emit::codegen::ast;
+use mutest_emit::codegen::mutation::{MutCtxt, MutLoc, Mutations, Subst, SubstDef, SubstLoc};
+use mutest_emit::smallvec::smallvec;
+
+pub const MATCH_GUARD_NEGATE: &str = "match_guard_negate";
+
+pub struct MatchGuardNegateMutation;
+
+impl Mutation for MatchGuardNegateMutation {
+    fn op_name(&self) -> &str { MATCH_GUARD_NEGATE }
+
+    fn display_name(&self) -> String {
+        "negate match arm guard".to_owned()
+    }
+}
+
+fn peel_parens(mut expr: &ast::Expr) -> &ast::Expr {
+    while let ast::ExprKind::Paren(inner_expr) = &expr.kind { expr = inner_expr; }
+    expr
+}
+
+fn contains_let(expr: &ast::Expr) -> bool {
+    match &peel_parens(expr).kind {
+        ast::ExprKind::Let(..) => true,
+        ast::ExprKind::Binary(bin_op, lhs, rhs) if matches!(bin_op.node, ast::BinOpKind::And) => contains_let(lhs) || contains_let(rhs),
+        _ => false,
+    }
+}
+
+/// Negate the guards of match arms, to test whether the conditions under which each match arm is taken are
+/// meaningfully tested.
+pub struct MatchGuardNegate;
+
+impl<'a> Operator<'a> for MatchGuardNegate {
+    type Mutation = MatchGuardNegateMutation;
+
+    fn try_apply(&self, mcx: &MutCtxt) -> Mutations<Self::Mutation> {
+        let MutCtxt { opts: _, tcx, crate_res: _, def_res: _, def_site: def, item_hir: _, body_res, location } = *mcx;
+
+        let MutLoc::FnBodyExpr(expr, _f) = location else { return Mutations::none(); };
+
+        // NOTE: Guards are mutated directly, rather than through their match expression,
+        //       so that the mutation only spans the guard expression.
+        let Some(expr_hir) = body_res.hir_expr(expr) else { unreachable!() };
+        let hir::Node::Arm(arm_hir) = tcx.parent_hir_node(expr_hir.hir_id) else { return Mutations::none(); };
+        if !arm_hir.guard.is_some_and(|guard_hir| guard_hir.hir_id == expr_hir.hir_id) { return Mutations::none(); }
+
+        // Negating constant guards produces trivial mutations.
+        if let ast::ExprKind::Lit(lit) = &peel_parens(expr).kind && let ast::token::LitKind::Bool = lit.kind { return Mutations::none(); }
+        // Guards with let bindings (i.e. `if let` guards) cannot be negated.
+        if contains_let(expr) { return Mutations::none(); }
+
+        let negated_guard = ast::mk::expr_not(def, ast::mk::expr_paren(def, Box::new(expr.clone())));
+
+        Mutations::new_one(MatchGuardNegateMutation, smallvec![
+            SubstDef::new(
+                SubstLoc::Replace(expr.id, expr.span),
+                Subst::AstExpr(*negated_guard),
+            ),
+        ])
+    }
+}
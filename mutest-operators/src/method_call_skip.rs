@@ -0,0 +1,78 @@
+use mutest_emit::{Mutation, Operator};
+use mutest_emit::analysis::hir;
+use mutest_emit::analysis::res;
+use mutest_emit::analysis::ty;
+use mutest_emit::codegen::ast;
+use mutest_emit::codegen::mutation::{MutCtxt, MutLoc, Mutations, Subst, SubstDef, SubstLoc};
+use mutest_emit::smallvec::smallvec;
+
+pub const METHOD_CALL_SKIP: &str = "method_call_skip";
+
+pub struct MethodCallSkipMutation {
+    pub callee_path: String,
+}
+
+impl Mutation for MethodCallSkipMutation {
+    fn op_name(&self) -> &str { METHOD_CALL_SKIP }
+
+    fn display_name(&self) -> String {
+        format!("skip call to `{callee}` and replace it with its receiver",
+            callee = self.callee_path
+        )
+    }
+
+    fn span_label(&self) -> String {
+        "skip call and replace it with its receiver".to_owned()
+    }
+}
+
+/// Replace method calls with their receiver, if the receiver has the same type as the return value of the call,
+/// to test whether the transformation performed by the method (e.g. `str::trim`) is meaningfully tested.
+pub struct MethodCallSkip;
+
+impl<'a> Operator<'a> for MethodCallSkip {
+    type Mutation = MethodCallSkipMutation;
+
+    fn try_apply(&self, mcx: &MutCtxt) -> Mutations<Self::Mutation> {
+        let MutCtxt { opts: _, tcx, crate_res: _, def_res: _, def_site: _, item_hir: f_hir, body_res, location } = *mcx;
+
+        let MutLoc::FnBodyExpr(expr, _f) = location else { return Mutations::none(); };
+
+        let ast::ExprKind::MethodCall(method_call) = &expr.kind else { return Mutations::none(); };
+
+        let Some(body_hir) = f_hir.body else { return Mutations::none(); };
+        let typeck = tcx.typeck_body(body_hir.id());
+
+        let Some(expr_hir) = body_res.hir_expr(expr) else { unreachable!() };
+        let hir::ExprKind::MethodCall(_, receiver_hir, _, _) = expr_hir.kind else { unreachable!() };
+
+        // NOTE: The receiver must have the exact type of the call's value, so that the replacement
+        //       does not rely on any coercions to be applied at the call site.
+        let expr_ty = typeck.expr_ty(expr_hir);
+        let receiver_ty = typeck.expr_ty(receiver_hir);
+        if tcx.erase_and_anonymize_regions(expr_ty) != tcx.erase_and_anonymize_regions(receiver_ty) { return Mutations::none(); }
+
+        // NOTE: The replacement always moves the receiver, while the original call may only borrow it
+        //       through auto-referencing (e.g. `s.clone()`). Moving a place which is used later on would
+        //       result in a borrow checker error, so non-`Copy` places are only replaced if the call
+        //       already moved them.
+        let receiver_moved_by_call = typeck.expr_adjustments(receiver_hir).is_empty();
+        let typing_env = ty::TypingEnv::post_analysis(tcx, f_hir.owner_id.def_id);
+        if !receiver_moved_by_call && receiver_hir.is_place_expr(|_| true) && !receiver_ty.is_copy_modulo_regions(tcx, typing_env) {
+            return Mutations::none();
+        }
+
+        let Some((callee, _)) = res::callee(typeck, expr_hir) else { return Mutations::none(); };
+
+        let mutation = Self::Mutation {
+            callee_path: tcx.def_path_str(callee),
+        };
+
+        Mutations::new_one(mutation, smallvec![
+            SubstDef::new(
+                SubstLoc::Replace(expr.id, expr.span),
+                Subst::AstExpr((*method_call.receiver).clone()),
+            ),
+        ])
+    }
+}
@@ -0,0 +1,71 @@
+use mutest_emit::{Mutation, Operator};
+use mutest_emit::analysis::res;
+use mutest_emit::analysis::ty;
+use mutest_emit::codegen::ast;
+use mutest_emit::codegen::mutation::{MutCtxt, MutLoc, Mutations, Subst, SubstDef, SubstLoc};
+use mutest_emit::codegen::symbols::path;
+use mutest_emit::thin_vec::thin_vec;
+use mutest_emit::smallvec::smallvec;
+
+pub const RETURN_VALUE_DEFAULT: &str = "return_value_default";
+
+pub struct ReturnValueDefaultMutation;
+
+impl Mutation for ReturnValueDefaultMutation {
+    fn op_name(&self) -> &str { RETURN_VALUE_DEFAULT }
+
+    fn display_name(&self) -> String {
+        "replace returned value with `Default::default()`".to_owned()
+    }
+}
+
+/// Replace the values of explicit `return` expressions with `Default::default()`, to test whether the values
+/// returned early from functions are meaningfully tested.
+pub struct ReturnValueDefault;
+
+impl<'a> Operator<'a> for ReturnValueDefault {
+    type Mutation = ReturnValueDefaultMutation;
+
+    fn try_apply(&self, mcx: &MutCtxt) -> Mutations<Self::Mutation> {
+        let MutCtxt { opts: _, tcx, crate_res: _, def_res: _, def_site: def, item_hir: f_hir, body_res, location } = *mcx;
+
+        let MutLoc::FnBodyExpr(expr, _f) = location else { return Mutations::none(); };
+
+        let ast::ExprKind::Ret(Some(ret_expr)) = &expr.kind else { return Mutations::none(); };
+
+        let Some(body_hir) = f_hir.body else { return Mutations::none(); };
+        let typeck = tcx.typeck_body(body_hir.id());
+
+        let Some(ret_expr_hir) = body_res.hir_expr(ret_expr) else { return Mutations::none(); };
+
+        // NOTE: All mutations are compiled into the same program, so the replacement must type-check.
+        //       We can only return `Default::default()` if the returned type implements `Default`.
+        let caller_def_id = f_hir.owner_id.def_id;
+        let ret_ty = typeck.expr_ty(ret_expr_hir);
+        if ret_ty == tcx.types.unit || ret_ty == tcx.types.never { return Mutations::none(); }
+        if !ty::impls_trait(tcx, caller_def_id, ret_ty, res::traits::Default(tcx), vec![]) { return Mutations::none(); }
+
+        // Avoid replacing values already returned with `Default::default()`.
+        if let Some((callee, _)) = res::callee(typeck, ret_expr_hir) && callee == res::fns::default(tcx) { return Mutations::none(); }
+
+        // Avoid returning `Default::default()` from the type's own `Default::default` implementation,
+        // which would result in infinite recursion.
+        let ty_default_generic_args = tcx.mk_args_trait(ret_ty, vec![]);
+        let typing_env = ty::TypingEnv::post_analysis(tcx, caller_def_id);
+        if let Some(ty_default_impl) = ty::Instance::try_resolve(tcx, typing_env, res::fns::default(tcx), ty_default_generic_args).ok().flatten()
+            && ty_default_impl.def_id() == caller_def_id.to_def_id()
+        {
+            return Mutations::none();
+        }
+
+        // Default::default()
+        let default = ast::mk::expr_call_path(def, path::default(def), thin_vec![]);
+
+        Mutations::new_one(ReturnValueDefaultMutation, smallvec![
+            SubstDef::new(
+                SubstLoc::Replace(ret_expr.id, ret_expr.span),
+                Subst::AstExpr(*default),
+            ),
+        ])
+    }
+}
@@ -0,0 +1,53 @@
+use mutest_emit::{Mutation, Operator};
+use mutest_emit::codegen::ast;
+use mutest_emit::codegen::mutation::{MutCtxt, MutLoc, Mutations, Subst, SubstDef, SubstLoc};
+use mutest_emit::codegen::symbols::path;
+use mutest_emit::smallvec::smallvec;
+use mutest_emit::thin_vec::thin_vec;
+
+pub const TRY_OP_REMOVE: &str = "try_op_remove";
+
+pub struct TryOpRemoveMutation;
+
+impl Mutation for TryOpRemoveMutation {
+    fn op_name(&self) -> &str { TRY_OP_REMOVE }
+
+    fn display_name(&self) -> String {
+        "remove error propagation operator `?`".to_owned()
+    }
+}
+
+/// Remove the error propagation operator `?`, ignoring the error, to test whether the error paths of fallible
+/// operations are meaningfully tested.
+pub struct TryOpRemove;
+
+impl<'a> Operator<'a> for TryOpRemove {
+    type Mutation = TryOpRemoveMutation;
+
+    fn try_apply(&self, mcx: &MutCtxt) -> Mutations<Self::Mutation> {
+        let MutCtxt { opts: _, tcx, crate_res: _, def_res: _, def_site: def, item_hir: f_hir, body_res, location } = *mcx;
+
+        let MutLoc::FnBodyExpr(expr, _f) = location else { return Mutations::none(); };
+
+        let ast::ExprKind::Try(inner_expr) = &expr.kind else { return Mutations::none(); };
+
+        let Some(body_hir) = f_hir.body else { return Mutations::none(); };
+        let typeck = tcx.typeck_body(body_hir.id());
+
+        // NOTE: All mutations are compiled into the same program, so the replacement must type-check.
+        //       The type of the fallible value always differs from the type of its output, so we can only
+        //       remove the operator if its output is the unit type, in which case the fallible value is dropped instead.
+        let Some(expr_hir) = body_res.hir_expr(expr) else { unreachable!() };
+        if typeck.expr_ty(expr_hir) != tcx.types.unit { return Mutations::none(); }
+
+        // ::core::mem::drop($inner_expr)
+        let dropped_inner_expr = ast::mk::expr_call_path(def, path::drop(def), thin_vec![inner_expr.clone()]);
+
+        Mutations::new_one(TryOpRemoveMutation, smallvec![
+            SubstDef::new(
+                SubstLoc::Replace(expr.id, expr.span),
+                Subst::AstExpr(*dropped_inner_expr),
+            ),
+        ])
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::hash::{DefaultHasher, Hasher};
+use std::path::Path;
+use std::time::Instant;
+
+use mutest_json::evaluation_stream::{Event, EvaluationStreamHeader, MutationResult, MutationResultCrash, MutationResultEvent};
+
+use crate::harness::{MutationTestCrash, MutationTestResult, MutationTestResults};
+use crate::metadata::MutationMeta;
+use crate::test_runner;
+use crate::write::EvaluationStreamWriter;
+
+fn to_json_mutation_result(mutation_test_result: MutationTestResult) -> MutationResult {
+    match mutation_test_result {
+        MutationTestResult::Detected => MutationResult::Detected,
+        MutationTestResult::Undetected => MutationResult::Undetected,
+        MutationTestResult::TimedOut => MutationResult::TimedOut,
+        MutationTestResult::Crashed => MutationResult::Crashed,
+    }
+}
+
+fn from_json_mutation_result(mutation_result: MutationResult) -> MutationTestResult {
+    match mutation_result {
+        MutationResult::Detected => MutationTestResult::Detected,
+        MutationResult::Undetected => MutationTestResult::Undetected,
+        MutationResult::TimedOut => MutationTestResult::TimedOut,
+        MutationResult::Crashed => MutationTestResult::Crashed,
+    }
+}
+
+/// Hashes the contents of the running test binary.
+///
+/// The test binary contains both the tests and the mutations of the program,
+/// so any change to the tests or to the program results in a different hash.
+fn current_test_binary_hash() -> Option<String> {
+    let test_binary = fs::read(env::current_exe().ok()?).ok()?;
+
+    let mut hasher = DefaultHasher::new();
+    hasher.write(&test_binary);
+    Some(format!("{:016x}", hasher.finish()))
+}
+
+/// Mutation results read back from the contents of a checkpoint file.
+struct RestoredResults {
+    results: HashMap<u32, MutationResultEvent>,
+    invalidated_results_count: usize,
+}
+
+/// Reads back the mutation results recorded in the contents of a checkpoint file, keyed by mutation ID.
+/// Returns [`None`] if the checkpoint file is incompatible.
+///
+/// All recorded results are discarded if they were recorded by a different test binary,
+/// as both the mutation IDs and the results themselves are only valid for the same test binary.
+/// Results recorded for unknown mutations, or whose content hashes differ from those of the mutations
+/// with the same ID, are also discarded.
+fn restore_results(checkpoint_str: &str, mutation_content_hashes: &HashMap<u32, &str>, test_binary_hash: Option<&str>) -> Option<RestoredResults> {
+    let mut lines = checkpoint_str.lines();
+
+    let header = lines.next().and_then(|line| serde_json::from_str::<EvaluationStreamHeader>(line).ok())?;
+    if header.format_version != mutest_json::FORMAT_VERSION { return None; }
+
+    let mut results = HashMap::new();
+    let mut invalidated_results_count = 0;
+
+    for line in lines {
+        // NOTE: The last line may be incomplete if the previous evaluation was interrupted while writing it.
+        let Ok(Event::MutationResult(event)) = serde_json::from_str::<Event>(line) else { continue; };
+
+        let is_valid = test_binary_hash.is_some() && event.test_binary_hash.as_deref() == test_binary_hash
+            && mutation_content_hashes.get(&event.mutation_id.0).is_some_and(|&content_hash| {
+                event.content_hash.as_ref().is_some_and(|event_content_hash| event_content_hash.0 == content_hash)
+            });
+        if is_valid {
+            results.insert(event.mutation_id.0, event);
+        } else {
+            invalidated_results_count += 1;
+        }
+    }
+
+    Some(RestoredResults { results, invalidated_results_count })
+}
+
+/// Evaluation checkpoint, recording the results of evaluated mutations as they complete,
+/// so that an interrupted evaluation can be resumed later.
+///
+/// The checkpoint file uses the JSONL format of the evaluation stream,
+/// consisting of [`Event::MutationResult`] events.
+pub struct Checkpoint {
+    writer: EvaluationStreamWriter,
+    test_binary_hash: Option<String>,
+    test_names: HashMap<String, test::TestName>,
+    restored_results: HashMap<u32, MutationResultEvent>,
+}
+
+impl Checkpoint {
+    /// Opens the checkpoint file at the given path, restoring any mutation results recorded in it.
+    ///
+    /// All recorded results are discarded if the test binary changed since the checkpoint was written,
+    /// e.g. because the source code of the program or its tests changed.
+    pub fn resume(path: &Path, mutations: &[&'static MutationMeta], tests: &[test_runner::Test], t_start: Instant) -> Self {
+        let test_binary_hash = current_test_binary_hash();
+        if test_binary_hash.is_none() {
+            println!("warning: cannot hash test binary, results recorded in checkpoint file `{}` cannot be restored", path.display());
+        }
+
+        let mutation_content_hashes = mutations.iter()
+            .map(|mutation| (mutation.id, mutation.content_hash))
+            .collect::<HashMap<_, _>>();
+
+        let mut restored_results = HashMap::<u32, MutationResultEvent>::new();
+        let mut invalidated_results_count = 0;
+
+        if let Ok(checkpoint_str) = fs::read_to_string(path) {
+            match restore_results(&checkpoint_str, &mutation_content_hashes, test_binary_hash.as_deref()) {
+                Some(restored) => {
+                    restored_results = restored.results;
+                    invalidated_results_count = restored.invalidated_results_count;
+                }
+                None => println!("warning: ignoring incompatible checkpoint file `{}`", path.display()),
+            }
+        }
+
+        if !restored_results.is_empty() || invalidated_results_count > 0 {
+            println!("resuming from checkpoint: {restored} mutations already evaluated; {invalidated} invalidated",
+                restored = restored_results.len(),
+                invalidated = invalidated_results_count,
+            );
+            println!();
+        }
+
+        // NOTE: Checkpoint files are read back when resuming, so they are never compressed.
+        let writer = EvaluationStreamWriter::new(path, false, t_start);
+
+        // Rewrite the valid results into the fresh checkpoint file, dropping invalidated results.
+        let mut restored_events = restored_results.values().collect::<Vec<_>>();
+        restored_events.sort_unstable_by_key(|event| event.mutation_id.0);
+        for event in restored_events {
+            writer.write_event(&Event::MutationResult(event.clone()));
+        }
+        writer.flush();
+
+        // NOTE: Results are keyed by the test names used by the harness, so that they match in detection matrices.
+        let test_names = tests.iter()
+            .map(|test| (test.desc.name.as_slice().to_owned(), test.desc.name.clone()))
+            .collect();
+
+        Self { writer, test_binary_hash, test_names, restored_results }
+    }
+
+    /// Returns the restored results of the mutation, if it was already evaluated according to the checkpoint.
+    pub fn restored_results(&self, mutation: &MutationMeta) -> Option<MutationTestResults> {
+        let event = self.restored_results.get(&mutation.id)?;
+
+        let results_per_test = event.test_results.iter()
+            .filter_map(|(test_name, &mutation_result)| {
+                let test_name = self.test_names.get(test_name)?;
+                Some((test_name.clone(), Some(from_json_mutation_result(mutation_result))))
+            })
+            .collect();
+
+        let crashes = event.crashes.iter()
+            .filter_map(|crash| {
+                Some(MutationTestCrash {
+                    test_name: self.test_names.get(&crash.test_name)?.clone(),
+                    message: crash.message.clone(),
+                    output: crash.output.clone().into_bytes(),
+                })
+            })
+            .collect();
+
+        Some(MutationTestResults {
+            result: from_json_mutation_result(event.mutation_result),
+            results_per_test,
+            crashes,
+        })
+    }
+
+    /// Records the results of the evaluated mutation in the checkpoint file.
+    pub fn record_results(&self, mutation: &MutationMeta, mutation_results: &MutationTestResults) {
+        let t = self.writer.timestamp();
+
+        self.writer.write_event(&Event::MutationResult(MutationResultEvent {
+            time: mutest_json::evaluation_stream::Nanos(t.as_nanos().try_into().expect("cannot fit timestamp in u64")),
+            mutation_id: mutest_json::mutations::MutationId(mutation.id),
+            content_hash: Some(mutest_json::mutations::MutationHash(mutation.content_hash.to_owned())),
+            test_binary_hash: self.test_binary_hash.clone(),
+            mutation_op: mutation.op_name.to_owned(),
+            display_name: mutation.display_name.to_owned(),
+            display_location: mutation.display_location.to_owned(),
+            mutation_result: to_json_mutation_result(mutation_results.result),
+            test_results: mutation_results.results_per_test.iter()
+                .filter_map(|(test_name, mutation_test_result)| Some((test_name.as_slice().to_owned(), to_json_mutation_result((*mutation_test_result)?))))
+                .collect(),
+            crashes: mutation_results.crashes.iter()
+                .map(|crash| MutationResultCrash {
+                    test_name: crash.test_name.as_slice().to_owned(),
+                    message: crash.message.clone(),
+                    output: String::from_utf8_lossy(&crash.output).into_owned(),
+                })
+                .collect(),
+        }));
+        // Results must be persisted immediately, as the evaluation may be interrupted at any point.
+        self.writer.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mutest_json::evaluation_stream::{Event, EvaluationStreamHeader, MutationResult, MutationResultEvent, Nanos};
+    use mutest_json::mutations::{MutationHash, MutationId};
+
+    use super::restore_results;
+
+    fn header(format_version: u32) -> String {
+        serde_json::to_string(&EvaluationStreamHeader { format_version }).unwrap()
+    }
+
+    fn mutation_result_event(mutation_id: u32, content_hash: &str, test_binary_hash: Option<&str>) -> MutationResultEvent {
+        MutationResultEvent {
+            time: Nanos(0),
+            mutation_id: MutationId(mutation_id),
+            content_hash: Some(MutationHash(content_hash.to_owned())),
+            test_binary_hash: test_binary_hash.map(str::to_owned),
+            mutation_op: "math_op_add_sub_swap".to_owned(),
+            display_name: "swap operator `+` for `-`".to_owned(),
+            display_location: "src/lib.rs:1:1: 1:6".to_owned(),
+            mutation_result: MutationResult::Detected,
+            test_results: HashMap::from_iter([("test".to_owned(), MutationResult::Detected)]),
+            crashes: vec![],
+        }
+    }
+
+    fn checkpoint_str(lines: &[String]) -> String {
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+
+    fn event_line(event: &MutationResultEvent) -> String {
+        serde_json::to_string(&Event::MutationResult(event.clone())).unwrap()
+    }
+
+    #[test]
+    fn test_restore_matching_result() {
+        let event = mutation_result_event(1, "a", Some("bin"));
+        let checkpoint_str = checkpoint_str(&[header(mutest_json::FORMAT_VERSION), event_line(&event)]);
+
+        let restored = restore_results(&checkpoint_str, &HashMap::from_iter([(1, "a"), (2, "b")]), Some("bin")).unwrap();
+        assert_eq!(restored.results, HashMap::from_iter([(1, event)]));
+        assert_eq!(restored.invalidated_results_count, 0);
+    }
+
+    #[test]
+    fn test_discard_results_of_different_test_binary() {
+        let checkpoint_str = checkpoint_str(&[
+            header(mutest_json::FORMAT_VERSION),
+            event_line(&mutation_result_event(1, "a", Some("other bin"))),
+            event_line(&mutation_result_event(2, "b", None)),
+        ]);
+
+        let restored = restore_results(&checkpoint_str, &HashMap::from_iter([(1, "a"), (2, "b")]), Some("bin")).unwrap();
+        assert!(restored.results.is_empty());
+        assert_eq!(restored.invalidated_results_count, 2);
+    }
+
+    #[test]
+    fn test_discard_results_without_current_test_binary_hash() {
+        let checkpoint_str = checkpoint_str(&[header(mutest_json::FORMAT_VERSION), event_line(&mutation_result_event(1, "a", None))]);
+
+        let restored = restore_results(&checkpoint_str, &HashMap::from_iter([(1, "a")]), None).unwrap();
+        assert!(restored.results.is_empty());
+        assert_eq!(restored.invalidated_results_count, 1);
+    }
+
+    #[test]
+    fn test_discard_results_of_unknown_mutations() {
+        let checkpoint_str = checkpoint_str(&[
+            header(mutest_json::FORMAT_VERSION),
+            // Content hash differs from that of the mutation with the same ID.
+            event_line(&mutation_result_event(1, "c", Some("bin"))),
+            // Mutation ID is unknown.
+            event_line(&mutation_result_event(3, "a", Some("bin"))),
+        ]);
+
+        let restored = restore_results(&checkpoint_str, &HashMap::from_iter([(1, "a"), (2, "b")]), Some("bin")).unwrap();
+        assert!(restored.results.is_empty());
+        assert_eq!(restored.invalidated_results_count, 2);
+    }
+
+    #[test]
+    fn test_skip_truncated_last_line() {
+        let event = mutation_result_event(1, "a", Some("bin"));
+        let truncated_line = event_line(&mutation_result_event(2, "b", Some("bin")));
+        let truncated_line = truncated_line[..truncated_line.len() / 2].to_owned();
+        let checkpoint_str = format!("{}{truncated_line}", checkpoint_str(&[header(mutest_json::FORMAT_VERSION), event_line(&event)]));
+
+        let restored = restore_results(&checkpoint_str, &HashMap::from_iter([(1, "a"), (2, "b")]), Some("bin")).unwrap();
+        assert_eq!(restored.results, HashMap::from_iter([(1, event)]));
+    }
+
+    #[test]
+    fn test_reject_incompatible_format_version() {
+        let checkpoint_str = checkpoint_str(&[header(mutest_json::FORMAT_VERSION + 1), event_line(&mutation_result_event(1, "a", Some("bin")))]);
+
+        assert!(restore_results(&checkpoint_str, &HashMap::from_iter([(1, "a")]), Some("bin")).is_none());
+    }
+
+    #[test]
+    fn test_reject_missing_header() {
+        assert!(restore_results("", &HashMap::new(), Some("bin")).is_none());
+    }
+}
@@ -1,19 +1,36 @@
 use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::harness::MutationTestResult;
+
 pub enum Mode {
     Evaluate,
     Flakes { iterations_count: usize },
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatrixOrdering {
+    ById,
+    ByFile,
+    ByScore,
+}
+
 pub struct PrintOptions {
     pub detection_matrix: Option<()>,
     pub subsumption_matrix: Option<()>,
+    pub test_effectiveness: Option<()>,
+    pub matrix_ordering: MatrixOrdering,
 }
 
 pub struct WriteOptions {
     pub out_dir: PathBuf,
     pub eval_stream: Option<()>,
+    pub compress: bool,
+    pub filter: Option<Vec<MutationTestResult>>,
+}
+
+pub struct CheckpointOptions {
+    pub path: PathBuf,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -35,15 +52,91 @@ pub enum MutationIsolation {
     All,
 }
 
+/// How mutations with a particular result are counted towards the mutation score.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScoreCounting {
+    /// Count the mutations as detected.
+    Detected,
+    /// Count the mutations as undetected.
+    Undetected,
+    /// Exclude the mutations from the mutation score altogether.
+    Separate,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScoringOptions {
+    pub crashes: ScoreCounting,
+    pub timeouts: ScoreCounting,
+}
+
+impl ScoringOptions {
+    /// Computes the mutation score of the given mutation counts, where timed out and crashed mutations are
+    /// counted according to the scoring options.
+    ///
+    /// Returns `None` if no mutations count towards the mutation score.
+    pub fn mutation_score(&self, total_mutations_count: usize, timed_out_mutations_count: usize, crashed_mutations_count: usize, undetected_mutations_count: usize) -> Option<f64> {
+        // NOTE: Timed out and crashed mutations are counted as detected mutations by default.
+        let mut detected_count = total_mutations_count - undetected_mutations_count;
+        let mut scored_count = total_mutations_count;
+
+        for (score_counting, count) in [(self.timeouts, timed_out_mutations_count), (self.crashes, crashed_mutations_count)] {
+            match score_counting {
+                ScoreCounting::Detected => {}
+                ScoreCounting::Undetected => { detected_count -= count; }
+                ScoreCounting::Separate => { detected_count -= count; scored_count -= count; }
+            }
+        }
+
+        match scored_count {
+            0 => None,
+            _ => Some(detected_count as f64 / scored_count as f64),
+        }
+    }
+}
+
 pub struct Options {
     pub mode: Mode,
     pub verbosity: u8,
     pub report_timings: bool,
     pub print_opts: PrintOptions,
     pub write_opts: Option<WriteOptions>,
+    pub checkpoint_opts: Option<CheckpointOptions>,
     pub exhaustive: bool,
+    pub fail_fast: bool,
     pub test_ordering: TestOrdering,
     pub test_timeout: TestTimeout,
     pub mutation_isolation: MutationIsolation,
     pub use_thread_pool: bool,
+    pub deterministic_order: bool,
+    pub scoring_opts: ScoringOptions,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScoreCounting, ScoringOptions};
+
+    #[test]
+    fn test_mutation_score_default_counts_as_detected() {
+        let scoring_opts = ScoringOptions { crashes: ScoreCounting::Detected, timeouts: ScoreCounting::Detected };
+        // 10 mutations: 6 detected (1 timed out; 1 crashed); 4 undetected.
+        assert_eq!(scoring_opts.mutation_score(10, 1, 1, 4), Some(0.6));
+        assert_eq!(scoring_opts.mutation_score(0, 0, 0, 0), None);
+    }
+
+    #[test]
+    fn test_mutation_score_undetected() {
+        let scoring_opts = ScoringOptions { crashes: ScoreCounting::Undetected, timeouts: ScoreCounting::Detected };
+        assert_eq!(scoring_opts.mutation_score(10, 1, 1, 4), Some(0.5));
+
+        let scoring_opts = ScoringOptions { crashes: ScoreCounting::Undetected, timeouts: ScoreCounting::Undetected };
+        assert_eq!(scoring_opts.mutation_score(10, 1, 1, 4), Some(0.4));
+    }
+
+    #[test]
+    fn test_mutation_score_separate() {
+        let scoring_opts = ScoringOptions { crashes: ScoreCounting::Separate, timeouts: ScoreCounting::Separate };
+        assert_eq!(scoring_opts.mutation_score(10, 1, 1, 4), Some(0.5));
+        // Only crashed mutations, which are excluded from the score.
+        assert_eq!(scoring_opts.mutation_score(2, 0, 2, 0), None);
+    }
 }
@@ -1,5 +1,7 @@
+use std::cmp::Reverse;
 use std::iter;
 
+use crate::config::MatrixOrdering;
 use crate::data_structures::TestArray;
 use crate::harness::{MutationTestResult, MutationTestResults};
 use crate::test_runner;
@@ -48,10 +50,21 @@ impl MutationDetectionMatrix {
     }
 }
 
-pub fn print_mutation_detection_matrix(mutation_detection_matrix: &MutationDetectionMatrix, tests: &[test_runner::Test], warn_non_exhaustive: bool) {
-    // Tests are printed in name order.
+pub fn print_mutation_detection_matrix(mutation_detection_matrix: &MutationDetectionMatrix, tests: &[test_runner::Test], ordering: MatrixOrdering, warn_non_exhaustive: bool) {
+    // Tests are printed in name order, unless they are ordered by score.
+    // NOTE: Tests have no source location information at runtime, so ordering by file also uses their
+    //       names, which follow the module structure.
     let mut test_names = tests.iter().map(|test| test.desc.name.clone()).collect::<Vec<_>>();
     test_names.sort_unstable_by(|test_name_a, test_name_b| Ord::cmp(test_name_a.as_slice(), test_name_b.as_slice()));
+    if let MatrixOrdering::ByScore = ordering {
+        // Tests which detect the most mutations are printed first.
+        test_names.sort_by_cached_key(|test_name| {
+            let detections_count = mutation_detection_matrix.iter_test_detections(test_name)
+                .filter(|(_, mutation_test_result)| matches!(mutation_test_result, Some(MutationTestResult::Detected | MutationTestResult::Crashed | MutationTestResult::TimedOut)))
+                .count();
+            Reverse(detections_count)
+        });
+    }
 
     let test_name_w = test_names.iter().map(|test_name| test_name.as_slice().len()).max().unwrap_or(0);
 
@@ -0,0 +1,97 @@
+use std::cmp::Reverse;
+
+use crate::detections::MutationDetectionMatrix;
+use crate::harness::MutationTestResult;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestEffectiveness {
+    pub test_name: test::TestName,
+    /// Number of mutations detected by the test.
+    pub detections_count: usize,
+    /// Number of mutations detected by the test, and by no other test.
+    pub unique_detections_count: usize,
+}
+
+fn is_detection(mutation_test_result: Option<MutationTestResult>) -> bool {
+    matches!(mutation_test_result, Some(MutationTestResult::Detected | MutationTestResult::Crashed | MutationTestResult::TimedOut))
+}
+
+/// Computes the number of mutations detected by each test, and the number of mutations uniquely detected by each test.
+///
+/// Tests are ordered by their unique detections, then by their total detections, with the most effective tests first.
+pub fn compute_test_effectiveness(mutation_detection_matrix: &MutationDetectionMatrix, test_names: &[&test::TestName]) -> Vec<TestEffectiveness> {
+    let mut tests_effectiveness = test_names.iter()
+        .map(|&test_name| TestEffectiveness { test_name: test_name.clone(), detections_count: 0, unique_detections_count: 0 })
+        .collect::<Vec<_>>();
+
+    for mutation_test_results in &mutation_detection_matrix.inner {
+        let mut detecting_test_idxs = test_names.iter().enumerate()
+            .filter(|(_, test_name)| is_detection(mutation_test_results.results_per_test.get(**test_name).copied().flatten()))
+            .map(|(test_idx, _)| test_idx);
+
+        let Some(first_detecting_test_idx) = detecting_test_idxs.next() else { continue; };
+        tests_effectiveness[first_detecting_test_idx].detections_count += 1;
+
+        let mut unique = true;
+        for test_idx in detecting_test_idxs {
+            tests_effectiveness[test_idx].detections_count += 1;
+            unique = false;
+        }
+        if unique { tests_effectiveness[first_detecting_test_idx].unique_detections_count += 1; }
+    }
+
+    tests_effectiveness.sort_by(|a, b| Ord::cmp(a.test_name.as_slice(), b.test_name.as_slice()));
+    tests_effectiveness.sort_by_key(|test_effectiveness| Reverse((test_effectiveness.unique_detections_count, test_effectiveness.detections_count)));
+
+    tests_effectiveness
+}
+
+pub fn print_test_effectiveness(tests_effectiveness: &[TestEffectiveness], warn_non_exhaustive: bool) {
+    let unique_w = "unique".len().max(tests_effectiveness.iter().map(|t| t.unique_detections_count.checked_ilog10().unwrap_or(0) as usize + 1).max().unwrap_or(0));
+    let detected_w = "detected".len().max(tests_effectiveness.iter().map(|t| t.detections_count.checked_ilog10().unwrap_or(0) as usize + 1).max().unwrap_or(0));
+
+    println!("{:>unique_w$}  {:>detected_w$}  test", "unique", "detected");
+    for test_effectiveness in tests_effectiveness {
+        println!("{unique:>unique_w$}  {detected:>detected_w$}  {test_name}",
+            unique = test_effectiveness.unique_detections_count,
+            detected = test_effectiveness.detections_count,
+            test_name = test_effectiveness.test_name.as_slice(),
+        );
+    }
+    println!();
+
+    let tests_without_unique_detections_count = tests_effectiveness.iter().filter(|t| t.unique_detections_count == 0).count();
+    println!("tests without unique detections: {tests_without_unique_detections_count} out of {total} tests",
+        total = tests_effectiveness.len(),
+    );
+    println!();
+
+    if warn_non_exhaustive {
+        println!("warning: unique detections are inaccurate as not all tests were evaluated, rerun with `--exhaustive`");
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::detections::MutationDetectionMatrix;
+    use crate::harness::MutationTestResult;
+
+    use super::compute_test_effectiveness;
+
+    #[test]
+    fn test_compute_test_effectiveness() {
+        let [a, b, c] = ["a", "b", "c"].map(test::TestName::StaticTestName);
+
+        let mut mutation_detection_matrix = MutationDetectionMatrix::new(3);
+        mutation_detection_matrix.insert(1, MutationTestResult::Detected, [(a.clone(), Some(MutationTestResult::Detected)), (b.clone(), Some(MutationTestResult::Crashed))]);
+        mutation_detection_matrix.insert(2, MutationTestResult::Detected, [(a.clone(), Some(MutationTestResult::Undetected)), (b.clone(), Some(MutationTestResult::TimedOut))]);
+        mutation_detection_matrix.insert(3, MutationTestResult::Undetected, [(a.clone(), Some(MutationTestResult::Undetected)), (c.clone(), None)]);
+
+        let tests_effectiveness = compute_test_effectiveness(&mutation_detection_matrix, &[&a, &b, &c]);
+        let summary = tests_effectiveness.iter()
+            .map(|t| (t.test_name.as_slice(), t.unique_detections_count, t.detections_count))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, vec![("b", 1, 2), ("a", 0, 1), ("c", 0, 0)]);
+    }
+}
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::convert::Infallible;
 use std::env;
 use std::fmt::{self, Debug};
+use std::io::{self, IsTerminal};
 use std::iter;
 use std::path::PathBuf;
 use std::process;
@@ -12,14 +13,16 @@ use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
 
+use crate::checkpoint::Checkpoint;
 use crate::config::{self, Options};
 use crate::detections::{MutationDetectionMatrix, print_mutation_detection_matrix};
+use crate::effectiveness::{compute_test_effectiveness, print_test_effectiveness};
 use crate::flakiness::{MutationFlakinessMatrix, print_mutation_flakiness_epilogue, print_mutation_flakiness_matrix};
 use crate::metadata::{self, CargoTargetKind, ExternalTestsExtra, MetaMutant, Mutant, MutationMeta, MutationParallelism, MutationSafety, StandaloneMutantMeta, SubstLocIdx, SubstMap, SubstMeta, TestSuite};
 use crate::subsumption::{MutationSubsumptionMatrix, print_mutation_subsumption_matrix};
 use crate::test_runner;
 use crate::thread_pool::ThreadPool;
-use crate::write::{EvaluationStreamWriter, write_evaluation};
+use crate::write::{EvaluationStreamWriter, write_evaluation, write_filtered_mutations};
 
 mod test {
     #![allow(unused_imports)]
@@ -306,10 +309,17 @@ pub enum MutationTestResult {
     Crashed,
 }
 
+pub struct MutationTestCrash {
+    pub test_name: test::TestName,
+    pub message: String,
+    pub output: Vec<u8>,
+}
+
 #[derive(Default)]
 pub struct MutationTestResults {
     pub result: MutationTestResult,
     pub results_per_test: HashMap<test::TestName, Option<MutationTestResult>>,
+    pub crashes: Vec<MutationTestCrash>,
 }
 
 fn run_tests(
@@ -333,6 +343,7 @@ fn run_tests(
         results.insert(mutation.id, MutationTestResults {
             result: MutationTestResult::Undetected,
             results_per_test: HashMap::with_capacity(metadata::reachable_tests_count(mutation, external_tests_extra)),
+            crashes: vec![],
         });
     }
 
@@ -384,8 +395,13 @@ fn run_tests(
                         mutation_results.result = MutationTestResult::Detected;
                     }
 
-                    test_runner::TestResult::CrashedMsg(_) => {
+                    test_runner::TestResult::CrashedMsg(ref crash_msg) => {
                         mutation_results.results_per_test.insert(test.desc.name.clone(), Some(MutationTestResult::Crashed));
+                        mutation_results.crashes.push(MutationTestCrash {
+                            test_name: test.desc.name.clone(),
+                            message: crash_msg.clone(),
+                            output: test.stdout.clone(),
+                        });
                         // Only mark mutation with crashed verdict if no other test has detected this mutation in a non-crashing way.
                         if mutation_results.result != MutationTestResult::Detected {
                             mutation_results.result = MutationTestResult::Crashed;
@@ -496,6 +512,7 @@ pub struct MutationAnalysisResults {
     pub crashed_mutations_count: usize,
     pub crashed_safe_mutations_count: usize,
     pub mutation_detection_matrix: MutationDetectionMatrix,
+    pub mutation_crashes: Vec<(u32, MutationTestCrash)>,
     pub mutation_op_stats: HashMap<&'static str, MutationOpStats>,
     pub duration: Duration,
 }
@@ -540,9 +557,16 @@ impl MutationAnalysisResults {
         }
 
         self.mutation_detection_matrix.insert(mutation.id, mutation_result.result, mutation_result.results_per_test.into_iter());
+        self.mutation_crashes.extend(mutation_result.crashes.into_iter().map(|crash| (mutation.id, crash)));
     }
 }
 
+/// Destinations that the results of the mutation analysis are recorded to as they are produced.
+struct MutationAnalysisRecorders<'a> {
+    eval_stream_writer: Option<EvaluationStreamWriter>,
+    checkpoint: Option<&'a Checkpoint>,
+}
+
 fn run_mutation_analysis<S: SubstMap>(
     opts: &Options,
     tests: &[test_runner::Test],
@@ -550,7 +574,7 @@ fn run_mutation_analysis<S: SubstMap>(
     meta_mutant: &'static MetaMutant<S>,
     thread_pool: Option<ThreadPool>,
     lingering_test_monitoring_thread: Arc<LingeringTestMonitoringThread>,
-    eval_stream_writer: Option<EvaluationStreamWriter>,
+    recorders: MutationAnalysisRecorders,
 ) -> MutationAnalysisResults {
     let mut results = MutationAnalysisResults {
         all_test_runs_failed_successfully: true,
@@ -563,6 +587,7 @@ fn run_mutation_analysis<S: SubstMap>(
         crashed_mutations_count: 0,
         crashed_safe_mutations_count: 0,
         mutation_detection_matrix: MutationDetectionMatrix::new(meta_mutant.mutations.len()),
+        mutation_crashes: vec![],
         mutation_op_stats: Default::default(),
         duration: Duration::ZERO,
     };
@@ -571,95 +596,155 @@ fn run_mutation_analysis<S: SubstMap>(
 
     match meta_mutant.mutation_parallelism {
         MutationParallelism::None(mutants) => {
+            let mut mutants = mutants.iter().collect::<Vec<_>>();
+            if opts.deterministic_order {
+                mutants.sort_by_key(|mutant| (mutant.mutation.content_hash, mutant.mutation.id));
+            }
+
             for mutant in mutants {
-                // SAFETY: Ideally, since the previous test runs all completed,
-                //         no other thread is running, no one else is reading from the handle.
-                //         Lingering test cases from previous test runs are forcibly terminated
-                //         before they try to read from the handle after the corresponding thread
-                //         has been marked inactive.
-                unsafe { meta_mutant.active_mutant_handle.replace(Some(mutant.substitutions.clone())); }
-
-                println!("applying mutation:");
-                print!("- ");
-                if opts.verbosity >= 1 {
-                    print!("{}: ", mutant.mutation.id);
-                }
-                println!("{unsafe_marker}[{op_name}] {display_name} at {display_location}",
-                    unsafe_marker = match mutant.mutation.safety {
-                        MutationSafety::Safe => "",
-                        MutationSafety::Tainted => "(tainted) ",
-                        MutationSafety::Unsafe => "(unsafe) ",
-                    },
-                    op_name = mutant.mutation.op_name,
-                    display_name = mutant.mutation.display_name,
-                    display_location = mutant.mutation.display_location,
-                );
-                println!();
+                let restored_mutation_result = recorders.checkpoint.and_then(|checkpoint| checkpoint.restored_results(mutant.mutation));
+                let mutation_result = match restored_mutation_result {
+                    Some(mutation_result) => {
+                        if opts.verbosity >= 1 {
+                            println!("{}: restored results from checkpoint", mutant.mutation.id);
+                            println!();
+                        }
+                        mutation_result
+                    }
+                    None => {
+                        // SAFETY: Ideally, since the previous test runs all completed,
+                        //         no other thread is running, no one else is reading from the handle.
+                        //         Lingering test cases from previous test runs are forcibly terminated
+                        //         before they try to read from the handle after the corresponding thread
+                        //         has been marked inactive.
+                        unsafe { meta_mutant.active_mutant_handle.replace(Some(mutant.substitutions.clone())); }
+
+                        println!("applying mutation:");
+                        print!("- ");
+                        if opts.verbosity >= 1 {
+                            print!("{}: ", mutant.mutation.id);
+                        }
+                        println!("{unsafe_marker}[{op_name}] {display_name} at {display_location}",
+                            unsafe_marker = match mutant.mutation.safety {
+                                MutationSafety::Safe => "",
+                                MutationSafety::Tainted => "(tainted) ",
+                                MutationSafety::Unsafe => "(unsafe) ",
+                            },
+                            op_name = mutant.mutation.op_name,
+                            display_name = mutant.mutation.display_name,
+                            display_location = mutant.mutation.display_location,
+                        );
+                        println!();
+
+                        let mut tests = clone_tests(tests.iter().filter(|test| is_reachable_test(mutant.mutation, &test.desc, external_tests_extra)));
+                        if let config::TestOrdering::MutationDistance = opts.test_ordering {
+                            prioritize_tests_by_distance(&mut tests, external_tests_extra, &[mutant.mutation]);
+                        }
 
-                let mut tests = clone_tests(tests.iter().filter(|test| is_reachable_test(mutant.mutation, &test.desc, external_tests_extra)));
-                if let config::TestOrdering::MutationDistance = opts.test_ordering {
-                    prioritize_tests_by_distance(&mut tests, external_tests_extra, &[mutant.mutation]);
-                }
+                        let (mut run_results, lingering_tests) = run_tests(tests, external_tests_extra, Mutant::Mutation(mutant), opts.exhaustive, opts.mutation_isolation, thread_pool.clone(), recorders.eval_stream_writer.clone(), opts.verbosity);
+                        lingering_test_monitoring_thread.submit_lingering_tests(lingering_tests);
 
-                let (mut run_results, lingering_tests) = run_tests(tests, external_tests_extra, Mutant::Mutation(mutant), opts.exhaustive, opts.mutation_isolation, thread_pool.clone(), eval_stream_writer.clone(), opts.verbosity);
-                lingering_test_monitoring_thread.submit_lingering_tests(lingering_tests);
+                        let Some(mutation_result) = run_results.remove(&mutant.mutation.id) else { unreachable!() };
+                        if let MutationTestResult::Undetected = mutation_result.result {
+                            print!("{}", mutant.mutation.undetected_diagnostic);
+                        }
+                        if let Some(checkpoint) = recorders.checkpoint {
+                            checkpoint.record_results(mutant.mutation, &mutation_result);
+                        }
+                        mutation_result
+                    }
+                };
+                results.record_mutation_results(mutant.mutation, mutation_result);
 
-                let Some(mutation_result) = run_results.remove(&mutant.mutation.id) else { unreachable!() };
-                if let MutationTestResult::Undetected = mutation_result.result {
-                    print!("{}", mutant.mutation.undetected_diagnostic);
+                if opts.fail_fast && !results.all_test_runs_failed_successfully {
+                    println!("stopping mutation evaluation early: found undetected mutation");
+                    println!();
+                    break;
                 }
-                results.record_mutation_results(mutant.mutation, mutation_result);
             }
         }
         MutationParallelism::Batched(batched_mutants) => {
+            let mut batched_mutants = batched_mutants.iter().collect::<Vec<_>>();
+            if opts.deterministic_order {
+                // NOTE: The contents of batches are determined at compile-time, only the batches themselves are reordered.
+                batched_mutants.sort_by_key(|batched_mutant| {
+                    (batched_mutant.mutations.iter().map(|mutation| mutation.content_hash).min(), batched_mutant.batch_id)
+                });
+            }
+
             for batched_mutant in batched_mutants {
-                // SAFETY: Ideally, since the previous test runs all completed,
-                //         no other thread is running, no one else is reading from the handle.
-                //         Lingering test cases from previous test runs are forcibly terminated
-                //         before they try to read from the handle after the corresponding thread
-                //         has been marked inactive.
-                unsafe { meta_mutant.active_mutant_handle.replace(Some(batched_mutant.substitutions.clone())); }
-
-                if opts.verbosity >= 1 {
-                    print!("{}: ", batched_mutant.batch_id);
-                }
-                match batched_mutant.mutations.len() {
-                    1 => println!("applying batch of 1 mutation:"),
-                    n => println!("applying batch of {n} mutations:"),
-                }
-                for mutation in batched_mutant.mutations {
-                    print!("- ");
+                // NOTE: Batches are only skipped if all of their mutations have been evaluated already.
+                let restored_mutation_results = recorders.checkpoint.and_then(|checkpoint| {
+                    batched_mutant.mutations.iter()
+                        .map(|mutation| checkpoint.restored_results(mutation))
+                        .collect::<Option<Vec<_>>>()
+                });
+                if let Some(mutation_results) = restored_mutation_results {
                     if opts.verbosity >= 1 {
-                        print!("{}: ", mutation.id);
+                        println!("{}: restored results from checkpoint", batched_mutant.batch_id);
+                        println!();
                     }
-                    println!("{unsafe_marker}[{op_name}] {display_name} at {display_location}",
-                        unsafe_marker = match mutation.safety {
-                            MutationSafety::Safe => "",
-                            MutationSafety::Tainted => "(tainted) ",
-                            MutationSafety::Unsafe => "(unsafe) ",
-                        },
-                        op_name = mutation.op_name,
-                        display_name = mutation.display_name,
-                        display_location = mutation.display_location,
-                    );
-                }
-                println!();
+                    for (mutation, mutation_result) in iter::zip(batched_mutant.mutations, mutation_results) {
+                        results.record_mutation_results(mutation, mutation_result);
+                    }
+                } else {
+                    // SAFETY: Ideally, since the previous test runs all completed,
+                    //         no other thread is running, no one else is reading from the handle.
+                    //         Lingering test cases from previous test runs are forcibly terminated
+                    //         before they try to read from the handle after the corresponding thread
+                    //         has been marked inactive.
+                    unsafe { meta_mutant.active_mutant_handle.replace(Some(batched_mutant.substitutions.clone())); }
 
-                let mut tests = clone_tests(tests.iter().filter(|test| batched_mutant.mutations.iter().any(|mutation| is_reachable_test(mutation, &test.desc, external_tests_extra))));
-                if let config::TestOrdering::MutationDistance = opts.test_ordering {
-                    prioritize_tests_by_distance(&mut tests, external_tests_extra, batched_mutant.mutations);
-                }
-                maximize_mutation_parallelism(&mut tests, external_tests_extra, batched_mutant.mutations);
+                    if opts.verbosity >= 1 {
+                        print!("{}: ", batched_mutant.batch_id);
+                    }
+                    match batched_mutant.mutations.len() {
+                        1 => println!("applying batch of 1 mutation:"),
+                        n => println!("applying batch of {n} mutations:"),
+                    }
+                    for mutation in batched_mutant.mutations {
+                        print!("- ");
+                        if opts.verbosity >= 1 {
+                            print!("{}: ", mutation.id);
+                        }
+                        println!("{unsafe_marker}[{op_name}] {display_name} at {display_location}",
+                            unsafe_marker = match mutation.safety {
+                                MutationSafety::Safe => "",
+                                MutationSafety::Tainted => "(tainted) ",
+                                MutationSafety::Unsafe => "(unsafe) ",
+                            },
+                            op_name = mutation.op_name,
+                            display_name = mutation.display_name,
+                            display_location = mutation.display_location,
+                        );
+                    }
+                    println!();
+
+                    let mut tests = clone_tests(tests.iter().filter(|test| batched_mutant.mutations.iter().any(|mutation| is_reachable_test(mutation, &test.desc, external_tests_extra))));
+                    if let config::TestOrdering::MutationDistance = opts.test_ordering {
+                        prioritize_tests_by_distance(&mut tests, external_tests_extra, batched_mutant.mutations);
+                    }
+                    maximize_mutation_parallelism(&mut tests, external_tests_extra, batched_mutant.mutations);
 
-                let (mut run_results, lingering_tests) = run_tests(tests, external_tests_extra, Mutant::Batch(batched_mutant), opts.exhaustive, opts.mutation_isolation, thread_pool.clone(), eval_stream_writer.clone(), opts.verbosity);
-                lingering_test_monitoring_thread.submit_lingering_tests(lingering_tests);
+                    let (mut run_results, lingering_tests) = run_tests(tests, external_tests_extra, Mutant::Batch(batched_mutant), opts.exhaustive, opts.mutation_isolation, thread_pool.clone(), recorders.eval_stream_writer.clone(), opts.verbosity);
+                    lingering_test_monitoring_thread.submit_lingering_tests(lingering_tests);
 
-                for mutation in batched_mutant.mutations {
-                    let Some(mutation_result) = run_results.remove(&mutation.id) else { unreachable!() };
-                    if let MutationTestResult::Undetected = mutation_result.result {
-                        print!("{}", mutation.undetected_diagnostic);
+                    for mutation in batched_mutant.mutations {
+                        let Some(mutation_result) = run_results.remove(&mutation.id) else { unreachable!() };
+                        if let MutationTestResult::Undetected = mutation_result.result {
+                            print!("{}", mutation.undetected_diagnostic);
+                        }
+                        if let Some(checkpoint) = recorders.checkpoint {
+                            checkpoint.record_results(mutation, &mutation_result);
+                        }
+                        results.record_mutation_results(mutation, mutation_result);
                     }
-                    results.record_mutation_results(mutation, mutation_result);
+                }
+
+                if opts.fail_fast && !results.all_test_runs_failed_successfully {
+                    println!("stopping mutation evaluation early: found undetected mutation");
+                    println!();
+                    break;
                 }
             }
         }
@@ -670,7 +755,14 @@ fn run_mutation_analysis<S: SubstMap>(
     results
 }
 
-fn print_mutation_analysis_epilogue(results: &MutationAnalysisResults, verbosity: u8) {
+fn format_mutation_score(mutation_score: Option<f64>) -> String {
+    match mutation_score {
+        None => "none".to_owned(),
+        Some(mutation_score) => format!("{:.2}%", mutation_score * 100_f64),
+    }
+}
+
+fn print_mutation_analysis_epilogue(results: &MutationAnalysisResults, scoring_opts: &config::ScoringOptions, verbosity: u8) {
     if verbosity >= 1 {
         let mut op_names = results.mutation_op_stats.keys().collect::<Vec<_>>();
         op_names.sort_unstable();
@@ -685,7 +777,7 @@ fn print_mutation_analysis_epilogue(results: &MutationAnalysisResults, verbosity
             let op_stats = results.mutation_op_stats.get(op_name).map(|s| *s).unwrap_or_default();
 
             println!("{op_name:>op_name_w$}: {score:>7}. {detected:>detected_w$} detected ({timed_out:>timed_out_w$} timed out; {crashed:>crashed_w$} crashed); {undetected:>undetected_w$} undetected",
-                score = format!("{:.2}%",(op_stats.total_mutations_count - op_stats.undetected_mutations_count) as f64 / op_stats.total_mutations_count as f64 * 100_f64),
+                score = format_mutation_score(scoring_opts.mutation_score(op_stats.total_mutations_count, op_stats.timed_out_mutations_count, op_stats.crashed_mutations_count, op_stats.undetected_mutations_count)),
                 detected = op_stats.total_mutations_count - op_stats.undetected_mutations_count,
                 timed_out = op_stats.timed_out_mutations_count,
                 crashed = op_stats.crashed_mutations_count,
@@ -697,10 +789,7 @@ fn print_mutation_analysis_epilogue(results: &MutationAnalysisResults, verbosity
     }
 
     println!("mutations: {score}. {detected} detected ({timed_out} timed out; {crashed} crashed); {undetected} undetected; {total} total",
-        score = match results.total_mutations_count {
-            0 => "none".to_owned(),
-            _ => format!("{:.2}%", (results.total_mutations_count - results.undetected_mutations_count) as f64 / results.total_mutations_count as f64 * 100_f64),
-        },
+        score = format_mutation_score(scoring_opts.mutation_score(results.total_mutations_count, results.timed_out_mutations_count, results.crashed_mutations_count, results.undetected_mutations_count)),
         detected = results.total_mutations_count - results.undetected_mutations_count,
         timed_out = results.timed_out_mutations_count,
         crashed = results.crashed_mutations_count,
@@ -708,10 +797,7 @@ fn print_mutation_analysis_epilogue(results: &MutationAnalysisResults, verbosity
         total = results.total_mutations_count,
     );
     println!("     safe: {score}. {detected} detected ({timed_out} timed out; {crashed} crashed); {undetected} undetected; {total} total",
-        score = match results.total_safe_mutations_count {
-            0 => "none".to_owned(),
-            _ => format!("{:.2}%", (results.total_safe_mutations_count - results.undetected_safe_mutations_count) as f64 / results.total_safe_mutations_count as f64 * 100_f64),
-        },
+        score = format_mutation_score(scoring_opts.mutation_score(results.total_safe_mutations_count, results.timed_out_safe_mutations_count, results.crashed_safe_mutations_count, results.undetected_safe_mutations_count)),
         detected = results.total_safe_mutations_count - results.undetected_safe_mutations_count,
         timed_out = results.timed_out_safe_mutations_count,
         crashed = results.crashed_safe_mutations_count,
@@ -719,10 +805,12 @@ fn print_mutation_analysis_epilogue(results: &MutationAnalysisResults, verbosity
         total = results.total_safe_mutations_count,
     );
     println!("   unsafe: {score}. {detected} detected ({timed_out} timed out; {crashed} crashed); {undetected} undetected; {total} total",
-        score = match results.total_mutations_count - results.total_safe_mutations_count {
-            0 => "none".to_owned(),
-            _ => format!("{:.2}%", ((results.total_mutations_count - results.total_safe_mutations_count) - (results.undetected_mutations_count - results.undetected_safe_mutations_count)) as f64 / (results.total_mutations_count - results.total_safe_mutations_count) as f64 * 100_f64),
-        },
+        score = format_mutation_score(scoring_opts.mutation_score(
+            results.total_mutations_count - results.total_safe_mutations_count,
+            results.timed_out_mutations_count - results.timed_out_safe_mutations_count,
+            results.crashed_mutations_count - results.crashed_safe_mutations_count,
+            results.undetected_mutations_count - results.undetected_safe_mutations_count,
+        )),
         detected = (results.total_mutations_count - results.total_safe_mutations_count) - (results.undetected_mutations_count - results.undetected_safe_mutations_count),
         timed_out = results.timed_out_mutations_count - results.timed_out_safe_mutations_count,
         crashed = results.crashed_mutations_count - results.crashed_safe_mutations_count,
@@ -731,6 +819,15 @@ fn print_mutation_analysis_epilogue(results: &MutationAnalysisResults, verbosity
     );
 }
 
+fn parse_score_counting(args: &[&str], prefix: &str) -> config::ScoreCounting {
+    match args.iter().flat_map(|arg| arg.strip_prefix(prefix)).next() {
+        None | Some("detected") => config::ScoreCounting::Detected,
+        Some("undetected") => config::ScoreCounting::Undetected,
+        Some("separate") => config::ScoreCounting::Separate,
+        Some(arg) => panic!("unexpected option: {prefix}{arg}"),
+    }
+}
+
 pub fn mutest_main(args: &[&str], tests: Vec<test::TestDescAndFn>, external_tests_extra: Option<&'static ExternalTestsExtra>, meta_mutant: &'static MetaMutant<impl SubstMap>) {
     let mode = match () {
         _ if let Some(flakes_arg) = args.iter().flat_map(|arg| arg.strip_prefix("--flakes=")).next() => {
@@ -750,6 +847,13 @@ pub fn mutest_main(args: &[&str], tests: Vec<test::TestDescAndFn>, external_test
         print_opts: config::PrintOptions {
             detection_matrix: args.contains(&"--print=detection-matrix").then_some(()),
             subsumption_matrix: args.contains(&"--print=subsumption-matrix").then_some(()),
+            test_effectiveness: args.contains(&"--print=test-effectiveness").then_some(()),
+            matrix_ordering: match args.iter().flat_map(|arg| arg.strip_prefix("--sort=")).next() {
+                None | Some("by-id") => config::MatrixOrdering::ById,
+                Some("by-file") => config::MatrixOrdering::ByFile,
+                Some("by-score") => config::MatrixOrdering::ByScore,
+                Some(arg) => panic!("unexpected option: --sort={arg}"),
+            },
         },
         write_opts: args.iter().flat_map(|arg| arg.strip_prefix("--Zwrite-json=")).next().map(|out_dir_str| {
             let mut out_dir = PathBuf::from(out_dir_str);
@@ -784,10 +888,33 @@ pub fn mutest_main(args: &[&str], tests: Vec<test::TestDescAndFn>, external_test
             config::WriteOptions {
                 out_dir,
                 eval_stream: args.contains(&"--Zwrite-json-eval-stream").then_some(()),
+                compress: args.contains(&"--Zwrite-json-compress"),
+                filter: {
+                    let filter = args.iter().flat_map(|arg| arg.strip_prefix("--Zwrite-json-filter="))
+                        .map(|detection| match detection {
+                            "detected" => MutationTestResult::Detected,
+                            "undetected" => MutationTestResult::Undetected,
+                            "timed-out" => MutationTestResult::TimedOut,
+                            "crashed" => MutationTestResult::Crashed,
+                            _ => panic!("unexpected option: --Zwrite-json-filter={detection}"),
+                        })
+                        .collect::<Vec<_>>();
+                    (!filter.is_empty()).then_some(filter)
+                },
             }
         }),
+        checkpoint_opts: args.iter().flat_map(|arg| arg.strip_prefix("--checkpoint=")).next().map(|path_str| {
+            config::CheckpointOptions { path: PathBuf::from(path_str) }
+        }),
         exhaustive: args.contains(&"--exhaustive"),
-        test_timeout: config::TestTimeout::Auto,
+        fail_fast: args.contains(&"--fail-fast"),
+        test_timeout: match args.iter().flat_map(|arg| arg.strip_prefix("--test-timeout=")).next() {
+            None => config::TestTimeout::Auto,
+            Some(arg) => match arg.parse::<f64>().ok().and_then(|secs| Duration::try_from_secs_f64(secs).ok()) {
+                Some(test_timeout) => config::TestTimeout::Explicit(test_timeout),
+                None => panic!("unexpected option: --test-timeout={arg}"),
+            },
+        },
         test_ordering: config::TestOrdering::ExecTime,
         mutation_isolation: match args.iter().flat_map(|arg| arg.strip_prefix("--isolate=")).next() {
             None | Some("unsafe") => config::MutationIsolation::Unsafe,
@@ -795,6 +922,11 @@ pub fn mutest_main(args: &[&str], tests: Vec<test::TestDescAndFn>, external_test
             Some(arg) => panic!("unexpected option: --isolate={arg}"),
         },
         use_thread_pool: args.contains(&"--use-thread-pool"),
+        deterministic_order: args.contains(&"--deterministic-order"),
+        scoring_opts: config::ScoringOptions {
+            crashes: parse_score_counting(args, "--count-crashes="),
+            timeouts: parse_score_counting(args, "--count-timeouts="),
+        },
     };
 
     let t_start = Instant::now();
@@ -802,7 +934,11 @@ pub fn mutest_main(args: &[&str], tests: Vec<test::TestDescAndFn>, external_test
 
     let eval_stream_writer = match &opts.write_opts {
         Some(write_opts) if let Some(()) = write_opts.eval_stream => {
-            Some(EvaluationStreamWriter::new(&write_opts.out_dir.join("evaluation.jsonl"), t_start))
+            let file_name = match write_opts.compress {
+                false => "evaluation.jsonl",
+                true => "evaluation.jsonl.gz",
+            };
+            Some(EvaluationStreamWriter::new(&write_opts.out_dir.join(file_name), write_opts.compress, t_start))
         }
         _ => None,
     };
@@ -901,26 +1037,37 @@ pub fn mutest_main(args: &[&str], tests: Vec<test::TestDescAndFn>, external_test
 
     match opts.mode {
         config::Mode::Evaluate => {
-            let results = run_mutation_analysis(&opts, &tests, external_tests_extra, meta_mutant, thread_pool, lingering_test_monitoring_thread.clone(), eval_stream_writer);
+            let checkpoint = opts.checkpoint_opts.as_ref().map(|checkpoint_opts| Checkpoint::resume(&checkpoint_opts.path, meta_mutant.mutations, &tests, t_start));
+
+            let results = run_mutation_analysis(&opts, &tests, external_tests_extra, meta_mutant, thread_pool, lingering_test_monitoring_thread.clone(), MutationAnalysisRecorders { eval_stream_writer, checkpoint: checkpoint.as_ref() });
 
             if let Some(write_opts) = &opts.write_opts {
                 let t_write_start = Instant::now();
-                write_evaluation(write_opts, &tests, &unmutated_test_exec_times, iter::once(&results), None, test_profiling_duration, t_start.elapsed());
+                write_evaluation(&opts, &tests, &unmutated_test_exec_times, iter::once(&results), None, test_profiling_duration, t_start.elapsed());
+                if let Some(filter) = &write_opts.filter {
+                    write_filtered_mutations(write_opts, filter, meta_mutant.mutations, &results);
+                }
                 write_duration += t_write_start.elapsed();
             }
 
             if let Some(()) = &opts.print_opts.detection_matrix {
-                print_mutation_detection_matrix(&results.mutation_detection_matrix, &tests, !opts.exhaustive);
+                print_mutation_detection_matrix(&results.mutation_detection_matrix, &tests, opts.print_opts.matrix_ordering, !opts.exhaustive);
             }
 
             if let Some(()) = &opts.print_opts.subsumption_matrix {
                 let mutation_subsumption_matrix = MutationSubsumptionMatrix::build(&results.mutation_detection_matrix, &tests);
-                print_mutation_subsumption_matrix(&mutation_subsumption_matrix, meta_mutant.mutations, !opts.exhaustive);
+                print_mutation_subsumption_matrix(&mutation_subsumption_matrix, meta_mutant.mutations, opts.print_opts.matrix_ordering, !opts.exhaustive);
+            }
+
+            if let Some(()) = &opts.print_opts.test_effectiveness {
+                let test_names = tests.iter().map(|test| &test.desc.name).collect::<Vec<_>>();
+                let tests_effectiveness = compute_test_effectiveness(&results.mutation_detection_matrix, &test_names);
+                print_test_effectiveness(&tests_effectiveness, !opts.exhaustive);
             }
 
             drop(lingering_test_monitoring_thread);
 
-            print_mutation_analysis_epilogue(&results, opts.verbosity);
+            print_mutation_analysis_epilogue(&results, &opts.scoring_opts, opts.verbosity);
 
             if opts.report_timings {
                 println!("\nfinished in {total:.2?} (profiling {profiling:.2?}; tests {tests:.2?}; write {write:.2?})",
@@ -945,18 +1092,24 @@ pub fn mutest_main(args: &[&str], tests: Vec<test::TestDescAndFn>, external_test
                 println!("running iteration {iteration} out of {iterations_count}");
                 println!();
 
-                let iteration_results = run_mutation_analysis(&opts, &tests, external_tests_extra, meta_mutant, thread_pool.clone(), lingering_test_monitoring_thread.clone(), eval_stream_writer.clone());
+                let iteration_results = run_mutation_analysis(&opts, &tests, external_tests_extra, meta_mutant, thread_pool.clone(), lingering_test_monitoring_thread.clone(), MutationAnalysisRecorders { eval_stream_writer: eval_stream_writer.clone(), checkpoint: None });
 
                 if let Some(()) = &opts.print_opts.detection_matrix {
-                    print_mutation_detection_matrix(&iteration_results.mutation_detection_matrix, &tests, !opts.exhaustive);
+                    print_mutation_detection_matrix(&iteration_results.mutation_detection_matrix, &tests, opts.print_opts.matrix_ordering, !opts.exhaustive);
                 }
 
                 if let Some(()) = &opts.print_opts.subsumption_matrix {
                     let mutation_subsumption_matrix = MutationSubsumptionMatrix::build(&iteration_results.mutation_detection_matrix, &tests);
-                    print_mutation_subsumption_matrix(&mutation_subsumption_matrix, meta_mutant.mutations, !opts.exhaustive);
+                    print_mutation_subsumption_matrix(&mutation_subsumption_matrix, meta_mutant.mutations, opts.print_opts.matrix_ordering, !opts.exhaustive);
                 }
 
-                print_mutation_analysis_epilogue(&iteration_results, opts.verbosity);
+                if let Some(()) = &opts.print_opts.test_effectiveness {
+                    let test_names = tests.iter().map(|test| &test.desc.name).collect::<Vec<_>>();
+                    let tests_effectiveness = compute_test_effectiveness(&iteration_results.mutation_detection_matrix, &test_names);
+                    print_test_effectiveness(&tests_effectiveness, !opts.exhaustive);
+                }
+
+                print_mutation_analysis_epilogue(&iteration_results, &opts.scoring_opts, opts.verbosity);
 
                 if opts.report_timings {
                     println!("\nfinished in {tests:.2?}",
@@ -971,9 +1124,9 @@ pub fn mutest_main(args: &[&str], tests: Vec<test::TestDescAndFn>, external_test
 
             drop(lingering_test_monitoring_thread);
 
-            if let Some(write_opts) = &opts.write_opts {
+            if opts.write_opts.is_some() {
                 let t_write_start = Instant::now();
-                write_evaluation(write_opts, &tests, &unmutated_test_exec_times, &results, None, test_profiling_duration, t_start.elapsed());
+                write_evaluation(&opts, &tests, &unmutated_test_exec_times, &results, None, test_profiling_duration, t_start.elapsed());
                 write_duration += t_write_start.elapsed();
             }
 
@@ -1011,10 +1164,27 @@ fn mutest_isolated_worker(test: test::TestDescAndFn, meta_mutant: &'static MetaM
     test_runner::run_test_in_spawned_subprocess(test);
 }
 
+fn use_colored_output(args: &[&str]) -> bool {
+    match args.iter().flat_map(|arg| arg.strip_prefix("--color=")).next() {
+        Some("always") => true,
+        Some("never") => false,
+        // NOTE: See https://no-color.org for the `NO_COLOR` convention.
+        _ => env::var_os("NO_COLOR").is_none_or(|no_color| no_color.is_empty()) && io::stdout().is_terminal(),
+    }
+}
+
 fn mutest_simulate_main<S: SubstMap>(args: &[&str], tests: Vec<test::TestDescAndFn>, mutant: &'static StandaloneMutantMeta<S>, active_mutant_handle: &'static ActiveMutantHandle<S>) {
     let _verbosity = args.iter().filter(|&arg| *arg == "-v").count() as u8;
     let report_timings = args.contains(&"--timings");
     let use_thread_pool = args.contains(&"--use-thread-pool");
+    let colored_output = use_colored_output(args);
+
+    let styled = |text: &'static str, ansi_style: &str| -> String {
+        match colored_output {
+            true => format!("\x1b[{ansi_style}m{text}\x1b[0m"),
+            false => text.to_owned(),
+        }
+    };
 
     let t_start = Instant::now();
 
@@ -1051,18 +1221,18 @@ fn mutest_simulate_main<S: SubstMap>(args: &[&str], tests: Vec<test::TestDescAnd
             test_runner::TestEvent::Result(test) => {
                 match test.result {
                     test_runner::TestResult::Ignored => {
-                        println!("test {} ... \x1b[1;33mignored\x1b[0m", test.desc.name.as_slice());
+                        println!("test {} ... {}", test.desc.name.as_slice(), styled("ignored", "1;33"));
                         ignored_tests_count += 1;
                     }
 
                     test_runner::TestResult::Ok => {
-                        println!("test {} ... \x1b[1;32mok\x1b[0m", test.desc.name.as_slice());
+                        println!("test {} ... {}", test.desc.name.as_slice(), styled("ok", "1;32"));
                     }
 
                     | test_runner::TestResult::Failed
                     | test_runner::TestResult::FailedMsg(_)
                     | test_runner::TestResult::CrashedMsg(_) => {
-                        println!("test {} ... \x1b[1;31mFAILED\x1b[0m", test.desc.name.as_slice());
+                        println!("test {} ... {}", test.desc.name.as_slice(), styled("FAILED", "1;31"));
                         failed_tests_count += 1;
                     }
 
@@ -1092,8 +1262,8 @@ fn mutest_simulate_main<S: SubstMap>(args: &[&str], tests: Vec<test::TestDescAnd
 
     println!("test result: {result}. {passed} passed; {failed} failed; {ignored} ignored",
         result = match failed_tests_count {
-            0 => "\x1b[1;32mok\x1b[0m",
-            _ => "\x1b[1;31mFAILED\x1b[0m",
+            0 => styled("ok", "1;32"),
+            _ => styled("FAILED", "1;31"),
         },
         passed = total_tests_count - failed_tests_count,
         failed = failed_tests_count,
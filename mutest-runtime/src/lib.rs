@@ -21,9 +21,11 @@ pub mod thread_pool;
 pub mod data_structures;
 
 pub mod detections;
+pub mod effectiveness;
 pub mod flakiness;
 pub mod subsumption;
 
+pub mod checkpoint;
 pub mod write;
 
 mod config;
@@ -121,6 +121,9 @@ pub struct MutationMeta {
     pub op_name: &'static str,
     pub display_name: &'static str,
     pub display_location: &'static str,
+    /// Content hash of the mutation, which is stable across compilations, unlike the mutation ID
+    /// (see `mutest_json::mutations::MutationHash`).
+    pub content_hash: &'static str,
     pub reachable_from: EntryPoints,
     pub undetected_diagnostic: &'static str,
 }
@@ -1,5 +1,7 @@
+use std::cmp::Reverse;
 use std::iter;
 
+use crate::config::MatrixOrdering;
 use crate::data_structures::TestArray;
 use crate::detections::MutationDetectionMatrix;
 use crate::harness::MutationTestResult;
@@ -130,10 +132,45 @@ impl MutationSubsumptionMatrix {
     }
 }
 
-pub fn print_mutation_subsumption_matrix(mutation_subsumption_matrix: &MutationSubsumptionMatrix, mutations: &[&MutationMeta], warn_non_exhaustive: bool) {
+/// Sort key of a mutation's display location (e.g. `src/lib.rs:3:5: 3:10`),
+/// ordering mutations by file, and then by line and column.
+fn display_location_sort_key(display_location: &str) -> (&str, usize, usize) {
+    let begin = display_location.split(": ").next().unwrap_or(display_location);
+    let mut parts = begin.rsplitn(3, ':');
+    let col = parts.next().and_then(|s| s.parse().ok());
+    let line = parts.next().and_then(|s| s.parse().ok());
+    match (parts.next(), line, col) {
+        (Some(path), Some(line), Some(col)) => (path, line, col),
+        _ => (display_location, 0, 0),
+    }
+}
+
+pub fn print_mutation_subsumption_matrix(mutation_subsumption_matrix: &MutationSubsumptionMatrix, mutations: &[&MutationMeta], ordering: MatrixOrdering, warn_non_exhaustive: bool) {
     let total_mutations_count = mutations.len();
     let mutation_id_w = total_mutations_count.checked_ilog10().unwrap_or(0) as usize + 1;
 
+    // NOTE: Only the rows are reordered, the columns are always printed in mutation ID order,
+    //       so that they match the mutation ID heading.
+    let mut row_mutation_ids = mutation_subsumption_matrix.iter_mutation_ids().collect::<Vec<_>>();
+    match ordering {
+        MatrixOrdering::ById => {}
+        MatrixOrdering::ByFile => {
+            row_mutation_ids.sort_by_cached_key(|&mutation_id| {
+                let mutation = mutations.iter().find(|mutation| mutation.id == mutation_id).unwrap();
+                display_location_sort_key(mutation.display_location)
+            });
+        }
+        MatrixOrdering::ByScore => {
+            // Mutations which subsume the most other mutations are printed first.
+            row_mutation_ids.sort_by_cached_key(|&mutation_id| {
+                let subsumes_count = mutation_subsumption_matrix.subsumptions_of(mutation_id).iter().enumerate()
+                    .filter(|&(other_mutation_idx, subsumption)| other_mutation_idx as u32 + 1 != mutation_id && subsumption.subsumes())
+                    .count();
+                Reverse(subsumes_count)
+            });
+        }
+    }
+
     // Print mutation ID numbers in 10's for matrix heading, like so `1        10        20...`.
     print!("{:w$}", "", w = mutation_id_w + 1);
     for mutation_idx in mutation_subsumption_matrix.iter_mutation_ids() {
@@ -160,7 +197,7 @@ pub fn print_mutation_subsumption_matrix(mutation_subsumption_matrix: &MutationS
     println!();
 
     // Print one matrix row for each mutation for mutation-mutation subsumptions.
-    for mutation_id in mutation_subsumption_matrix.iter_mutation_ids() {
+    for mutation_id in row_mutation_ids {
         print!("{:>mutation_id_w$} ", mutation_id);
 
         let mut subsumes_count: usize = 0;
@@ -211,7 +248,15 @@ pub fn print_mutation_subsumption_matrix(mutation_subsumption_matrix: &MutationS
 mod tests {
     use crate::harness::MutationTestResult;
 
-    use super::{MutationSubsumption, compute_mutation_subsumption};
+    use super::{MutationSubsumption, compute_mutation_subsumption, display_location_sort_key};
+
+    #[test]
+    fn test_display_location_sort_key() {
+        assert_eq!(display_location_sort_key("src/lib.rs:3:5: 3:10 (#0)"), ("src/lib.rs", 3, 5));
+        assert_eq!(display_location_sort_key("src/parser/mod.rs:120:17: 121:2"), ("src/parser/mod.rs", 120, 17));
+        assert!(display_location_sort_key("src/a.rs:9:1: 9:2") < display_location_sort_key("src/a.rs:10:1: 10:2"));
+        assert_eq!(display_location_sort_key("<unknown>"), ("<unknown>", 0, 0));
+    }
 
     #[test]
     fn test_either_result_sequence_empty_no_subsumption_relation() {
@@ -8,7 +8,10 @@ use std::sync::{Arc, Mutex};
 use std::thread::ThreadId;
 use std::time::{Duration, Instant};
 
-use crate::config::WriteOptions;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+
+use crate::config::{Options, WriteOptions};
 use crate::harness::{MutationAnalysisResults, MutationTestResult};
 use crate::flakiness::MutationFlakinessMatrix;
 use crate::metadata::MutationMeta;
@@ -16,15 +19,23 @@ use crate::test_runner;
 
 #[derive(Clone)]
 pub struct EvaluationStreamWriter {
-    buffered_file: Arc<Mutex<BufWriter<fs::File>>>,
+    buffered_file: Arc<Mutex<Box<dyn Write + Send>>>,
     t_start: Instant,
 }
 
 impl EvaluationStreamWriter {
-    pub fn new(path: &Path, t_start: Instant) -> Self {
+    /// Creates a new stream file at the given path, gzip-compressing its contents if requested.
+    ///
+    /// Flushing a compressed stream file completes the current compressed block,
+    /// so that the events written so far can be decompressed, even if the evaluation is interrupted.
+    /// The gzip trailer is written once the last clone of the writer is dropped.
+    pub fn new(path: &Path, compress: bool, t_start: Instant) -> Self {
         let file = fs::File::create(path).expect("cannot create stream file");
-        let buffered_file = Arc::new(Mutex::new(BufWriter::new(file)));
-        let eval_stream_writer = Self { buffered_file, t_start };
+        let buffered_file: Box<dyn Write + Send> = match compress {
+            false => Box::new(BufWriter::new(file)),
+            true => Box::new(GzEncoder::new(BufWriter::new(file), Compression::default())),
+        };
+        let eval_stream_writer = Self { buffered_file: Arc::new(Mutex::new(buffered_file)), t_start };
 
         eval_stream_writer.write_event(&mutest_json::evaluation_stream::EvaluationStreamHeader {
             format_version: mutest_json::FORMAT_VERSION,
@@ -44,6 +55,11 @@ impl EvaluationStreamWriter {
         writeln!(eval_stream_file.deref_mut(), "").expect("cannot write to stream file");
     }
 
+    pub fn flush(&self) {
+        let mut eval_stream_file = self.buffered_file.lock().unwrap();
+        eval_stream_file.flush().expect("cannot write to stream file");
+    }
+
     pub fn write_test_start(&self, mutation: &MutationMeta, test_desc: &test::TestDesc, thread_id: Option<ThreadId>) {
         let t = self.timestamp();
 
@@ -82,7 +98,7 @@ fn write_metadata<T: serde::Serialize>(write_opts: &WriteOptions, file_name: &st
 }
 
 pub fn write_evaluation<'a, I>(
-    write_opts: &WriteOptions,
+    opts: &Options,
     tests: &[test_runner::Test],
     unmutated_test_exec_times: &HashMap<test::TestName, Duration>,
     results: I,
@@ -93,6 +109,8 @@ pub fn write_evaluation<'a, I>(
 where
     I: IntoIterator<Item = &'a MutationAnalysisResults>,
 {
+    let Some(write_opts) = &opts.write_opts else { unreachable!() };
+
     let mut runtime_tests = mutest_json::IdxVec::with_capacity(tests.len());
     for test in tests {
         let runtime_test_id = runtime_tests.next_index();
@@ -104,6 +122,10 @@ where
         });
     }
 
+    let runtime_test_ids = runtime_tests.iter_enumerated()
+        .map(|(runtime_test_id, runtime_test)| (runtime_test.name.as_str(), runtime_test_id))
+        .collect::<HashMap<_, _>>();
+
     let mutation_runs = results.into_iter()
         .map(|run_results| {
             let mut overall_detections = mutest_json::IdxVec::<mutest_json::mutations::MutationId, _>::with_capacity(run_results.mutation_detection_matrix.inner.len());
@@ -137,12 +159,20 @@ where
                 test_detections,
             };
 
+            let crashes = run_results.mutation_crashes.iter()
+                .map(|(mutation_id, crash)| {
+                    mutest_json::evaluation::MutationCrash {
+                        mutation_id: mutest_json::mutations::MutationId(*mutation_id),
+                        runtime_test_id: *runtime_test_ids.get(crash.test_name.as_slice()).expect("crashed test not found amongst tests"),
+                        message: crash.message.clone(),
+                        output: String::from_utf8_lossy(&crash.output).into_owned(),
+                    }
+                })
+                .collect();
+
             mutest_json::evaluation::MutationRun {
                 all_mutations_detection_stats: mutest_json::evaluation::MutationDetectionStats {
-                    mutation_score: match run_results.total_mutations_count {
-                        0 => None,
-                        _ => Some((run_results.total_mutations_count - run_results.undetected_mutations_count) as f64 / run_results.total_mutations_count as f64),
-                    },
+                    mutation_score: opts.scoring_opts.mutation_score(run_results.total_mutations_count, run_results.timed_out_mutations_count, run_results.crashed_mutations_count, run_results.undetected_mutations_count),
                     total_mutations_count: run_results.total_mutations_count,
                     detected_mutations_count: run_results.total_mutations_count - run_results.undetected_mutations_count,
                     timed_out_mutations_count: run_results.timed_out_mutations_count,
@@ -150,10 +180,7 @@ where
                     undetected_mutations_count: run_results.undetected_mutations_count,
                 },
                 safe_mutations_detection_stats: mutest_json::evaluation::MutationDetectionStats {
-                    mutation_score: match run_results.total_safe_mutations_count {
-                        0 => None,
-                        _ => Some((run_results.total_safe_mutations_count - run_results.undetected_safe_mutations_count) as f64 / run_results.total_safe_mutations_count as f64),
-                    },
+                    mutation_score: opts.scoring_opts.mutation_score(run_results.total_safe_mutations_count, run_results.timed_out_safe_mutations_count, run_results.crashed_safe_mutations_count, run_results.undetected_safe_mutations_count),
                     total_mutations_count: run_results.total_safe_mutations_count,
                     detected_mutations_count: run_results.total_safe_mutations_count - run_results.undetected_safe_mutations_count,
                     timed_out_mutations_count: run_results.timed_out_safe_mutations_count,
@@ -161,10 +188,12 @@ where
                     undetected_mutations_count: run_results.undetected_safe_mutations_count,
                 },
                 unsafe_mutations_detection_stats: mutest_json::evaluation::MutationDetectionStats {
-                    mutation_score: match run_results.total_mutations_count - run_results.total_safe_mutations_count {
-                        0 => None,
-                        _ => Some(((run_results.total_mutations_count - run_results.total_safe_mutations_count) - (run_results.undetected_mutations_count - run_results.undetected_safe_mutations_count)) as f64 / (run_results.total_mutations_count - run_results.total_safe_mutations_count) as f64),
-                    },
+                    mutation_score: opts.scoring_opts.mutation_score(
+                        run_results.total_mutations_count - run_results.total_safe_mutations_count,
+                        run_results.timed_out_mutations_count - run_results.timed_out_safe_mutations_count,
+                        run_results.crashed_mutations_count - run_results.crashed_safe_mutations_count,
+                        run_results.undetected_mutations_count - run_results.undetected_safe_mutations_count,
+                    ),
                     total_mutations_count: run_results.total_mutations_count - run_results.total_safe_mutations_count,
                     detected_mutations_count: (run_results.total_mutations_count - run_results.total_safe_mutations_count) - (run_results.undetected_mutations_count - run_results.undetected_safe_mutations_count),
                     timed_out_mutations_count: run_results.timed_out_mutations_count - run_results.timed_out_safe_mutations_count,
@@ -174,10 +203,7 @@ where
                 per_op_mutation_detection_stats: run_results.mutation_op_stats.iter()
                     .map(|(&op_name, op_stats)| {
                         let op_mutation_detection_stats = mutest_json::evaluation::MutationDetectionStats {
-                            mutation_score: match op_stats.total_mutations_count {
-                                0 => None,
-                                _ => Some((op_stats.total_mutations_count - op_stats.undetected_mutations_count) as f64 / op_stats.total_mutations_count as f64),
-                            },
+                            mutation_score: opts.scoring_opts.mutation_score(op_stats.total_mutations_count, op_stats.timed_out_mutations_count, op_stats.crashed_mutations_count, op_stats.undetected_mutations_count),
                             total_mutations_count: op_stats.total_mutations_count,
                             detected_mutations_count: op_stats.total_mutations_count - op_stats.undetected_mutations_count,
                             timed_out_mutations_count: op_stats.timed_out_mutations_count,
@@ -188,6 +214,7 @@ where
                     })
                     .collect(),
                 mutation_detection_matrix,
+                crashes,
                 duration: run_results.duration,
             }
         })
@@ -236,3 +263,40 @@ where
         duration,
     });
 }
+
+fn to_json_mutation_detection(mutation_test_result: MutationTestResult) -> mutest_json::evaluation::MutationDetection {
+    match mutation_test_result {
+        MutationTestResult::Undetected => mutest_json::evaluation::MutationDetection::Undetected,
+        MutationTestResult::Detected => mutest_json::evaluation::MutationDetection::Detected,
+        MutationTestResult::Crashed => mutest_json::evaluation::MutationDetection::Crashed,
+        MutationTestResult::TimedOut => mutest_json::evaluation::MutationDetection::TimedOut,
+    }
+}
+
+pub fn write_filtered_mutations(
+    write_opts: &WriteOptions,
+    filter: &[MutationTestResult],
+    mutations: &[&MutationMeta],
+    results: &MutationAnalysisResults,
+) {
+    let filtered_mutations = results.mutation_detection_matrix.iter_detections()
+        .filter(|(_mutation_id, mutation_test_result)| filter.contains(mutation_test_result))
+        .map(|(mutation_id, mutation_test_result)| {
+            let mutation = mutations.iter().find(|mutation| mutation.id == mutation_id).expect("evaluated mutation not found amongst mutations");
+
+            mutest_json::evaluation::FilteredMutation {
+                mutation_id: mutest_json::mutations::MutationId(mutation_id),
+                mutation_op: mutation.op_name.to_owned(),
+                display_name: mutation.display_name.to_owned(),
+                display_location: mutation.display_location.to_owned(),
+                detection: to_json_mutation_detection(mutation_test_result),
+            }
+        })
+        .collect();
+
+    write_metadata(write_opts, "filtered_mutations.json", &mutest_json::evaluation::FilteredMutationsInfo {
+        format_version: mutest_json::FORMAT_VERSION,
+        detections: filter.iter().copied().map(to_json_mutation_detection).collect(),
+        mutations: filtered_mutations,
+    });
+}
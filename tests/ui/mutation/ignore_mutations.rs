@@ -0,0 +1,22 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: math_op_add_sub_swap
+//@ mutest-flags: --ignore-file=tests/ui/mutation/ignore_mutations.ignore
+
+fn f() {
+    let _ = 1 + 2;
+}
+
+fn g() {
+    let _ = 1 - 2;
+    // Identical to the ignored mutation above, but must not be ignored with it.
+    let _ = 1 - 2;
+}
+
+#[test]
+fn test() {
+    f();
+    g();
+}
@@ -0,0 +1,20 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: math_op_add_sub_swap
+//@ mutest-flags: --max-mutations-per-target=2
+
+fn f() {
+    let _ = 1 + 2 + 3 + 4 + 5;
+}
+
+fn g() {
+    let _ = 1 - 2;
+}
+
+#[test]
+fn test() {
+    f();
+    g();
+}
@@ -0,0 +1,19 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: bool_lit_cond_flip
+
+#![allow(unused)]
+
+fn f(x: bool) {
+    if true {}
+    while (false) {}
+    if x && true {}
+    let b = true;
+}
+
+#[test]
+fn test() {
+    f(false);
+}
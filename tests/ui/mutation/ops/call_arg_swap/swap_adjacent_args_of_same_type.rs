@@ -0,0 +1,54 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: call_arg_swap
+
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn new(start: u32, end: u32) -> Self {
+        Self { start, end }
+    }
+
+    fn shifted(&self, by: u32, limit: u32) -> Self {
+        Self { start: (self.start + by) % limit, end: (self.end + by) % limit }
+    }
+
+    fn scaled(&self, by: u32, factor: f64) -> Self {
+        Self { start: self.start * by, end: (self.end as f64 * factor) as u32 }
+    }
+}
+
+fn sub(a: i32, b: i32) -> i32 {
+    a - b
+}
+
+fn mix(a: i32, b: i32, c: i32) -> i32 {
+    a * 100 + b * 10 + c
+}
+
+fn join(a: String, b: String) -> String {
+    a + &b
+}
+
+fn f(x: i32, y: i32, s: String) {
+    let _ = sub(x, y);
+    let _ = mix(x, y, 0);
+    let _ = join(s.clone(), s);
+}
+
+fn g(start: u32, end: u32) {
+    let range = Range::new(start, end);
+    let _ = range.shifted(1, end);
+    let _ = range.scaled(2, 1.5);
+}
+
+#[test]
+fn test() {
+    f(1, 2, String::new());
+    g(1, 2);
+}
@@ -0,0 +1,18 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: int_lit_boundary
+
+fn f() {
+    let x = 5u32;
+    let y = 0_u8;
+    let z = 255_u8;
+    let _ = [1; 4];
+    let _ = (x, y, z);
+}
+
+#[test]
+fn test() {
+    f();
+}
@@ -0,0 +1,20 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: match_guard_negate
+
+fn f(v: Option<i32>) -> i32 {
+    match v {
+        Some(x) if x > 0 => x,
+        Some(x) if (x < -10 || x == -1) => -x,
+        Some(_) if true => 0,
+        Some(_) => 1,
+        None => 2,
+    }
+}
+
+#[test]
+fn test() {
+    f(Some(1));
+}
@@ -0,0 +1,46 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: method_call_skip
+
+#[derive(Default)]
+struct Config {
+    verbose: bool,
+}
+
+impl Config {
+    fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+}
+
+fn normalize(s: &str) -> &str {
+    s.trim()
+}
+
+fn magnitude(x: i32) -> i32 {
+    x.abs()
+}
+
+fn verbose_config() -> Config {
+    Config::default().verbose(true)
+}
+
+fn duplicate(s: String) -> (String, String) {
+    (s.clone(), s)
+}
+
+fn to_owned(s: &str) -> String {
+    s.to_owned()
+}
+
+#[test]
+fn test() {
+    normalize(" a ");
+    magnitude(-1);
+    verbose_config();
+    duplicate(String::new());
+    to_owned("a");
+}
@@ -0,0 +1,29 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: return_value_default
+
+struct NoDefault;
+
+fn f() -> i32 {
+    return 5;
+}
+
+fn g(v: &[u8]) -> Option<usize> {
+    for (i, &b) in v.iter().enumerate() {
+        if b == 0 { return Some(i); }
+    }
+    return Default::default();
+}
+
+fn h() -> NoDefault {
+    return NoDefault;
+}
+
+#[test]
+fn test() {
+    f();
+    g(&[1, 0]);
+    h();
+}
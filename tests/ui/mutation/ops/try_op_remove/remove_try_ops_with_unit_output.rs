@@ -0,0 +1,27 @@
+//@ print-mutations
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: try_op_remove
+
+#![allow(unused)]
+
+fn foo() -> Result<(), ()> {
+    Ok(())
+}
+
+fn bar() -> Result<i32, ()> {
+    Ok(1)
+}
+
+fn f() -> Result<(), ()> {
+    let x = foo()?;
+    foo()?;
+    let y = bar()?;
+    Ok(())
+}
+
+#[test]
+fn test() {
+    f().unwrap();
+}
@@ -0,0 +1,14 @@
+//@ build
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: math_op_add_sub_swap
+//@ mutest-flags: --Zprint-spans=tests/ui/print_spans.rs
+
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test]
+fn test_add() {
+    add(1, 2);
+}
@@ -0,0 +1,21 @@
+//@ print-target-summary
+//@ stdout
+//@ stderr: empty
+//@ mutation-operators: math_op_add_mul_swap, math_op_add_sub_swap
+
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn noop() {}
+
+#[test]
+fn test_add() {
+    assert_eq!(3, add(1, 2));
+    noop();
+}
+
+#[test]
+fn test_add_zero() {
+    assert_eq!(1, add(1, 0));
+}
@@ -0,0 +1,18 @@
+//@ print-untested-functions
+//@ stdout
+//@ stderr: empty
+
+#![allow(dead_code)]
+
+fn tested() {}
+
+fn untested() {}
+
+mod inner {
+    pub fn untested_inner() {}
+}
+
+#[test]
+fn test_tested() {
+    tested();
+}